@@ -0,0 +1,37 @@
+use hackerlog::*;
+use std::thread;
+
+fn main() -> std::io::Result<()> {
+    // Records are rendered on the calling thread and written by a background thread.
+    // The guard drains the queue when it goes out of scope at the end of main.
+    let guard = logger().enable_async(1024, OverflowPolicy::Block)?;
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            thread::spawn(move || {
+                for j in 0..5 {
+                    info!("Scanner {} probed host {}", i, j);
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    // Wait for everything queued so far to hit the terminal
+    guard.flush()?;
+    success!("All scanners done");
+
+    // A tiny queue that sheds load instead of stalling the hot path
+    let _guard = logger().enable_async(4, OverflowPolicy::DropOldest)?;
+    for i in 0..1000 {
+        debug!("Packet {}", i);
+        info!("Burst line {}", i);
+    }
+    logger().flush()?;
+    warn!("{} records were dropped", logger().dropped_records());
+
+    Ok(())
+}
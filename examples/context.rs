@@ -14,6 +14,8 @@ fn scan_ports(host: &str) {
 }
 
 fn main() {
+    // Global context shows up on every thread, the rest only on the thread that added it
+    let _session = logger().add_global_context("session", "pentest-42");
     let _req_id = logger().add_context("request_id", "12345");
 
     info!("Starting application"); // Includes request_id
@@ -21,4 +23,15 @@ fn main() {
     scan_ports("example.com"); // Includes request_id and temporarily host
 
     info!("Finished!"); // Only includes request_id again
+
+    std::thread::spawn(|| info!("Worker thread")) // Only includes session
+        .join()
+        .unwrap();
+
+    // Guards remove exactly their own entry, whatever order they're dropped in
+    let first = logger().add_context("first", "1");
+    let second = logger().add_context("second", "2");
+    drop(first);
+    info!("Only second is left");
+    drop(second);
 }
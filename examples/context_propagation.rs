@@ -0,0 +1,27 @@
+use hackerlog::*;
+use std::thread;
+
+fn main() {
+    let _req = logger().add_context("request_id", "12345");
+    let _host = logger().add_context("target_host", "10.0.0.1");
+
+    // Workers keep the correlation fields of the thread that spawned them
+    let handles: Vec<_> = (0..3)
+        .map(|i| {
+            spawn_with_context(move || {
+                let _worker = logger().add_context("worker", i.to_string());
+                info!("Scanning chunk {}", i);
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    // Snapshots work with any other way of running code on a thread
+    let snapshot = ContextSnapshot::capture();
+    thread::scope(|scope| {
+        scope.spawn(snapshot.wrap(|| info!("Scoped thread")));
+    });
+}
@@ -42,4 +42,15 @@ fn main() {
     })
     .join()
     .unwrap();
+
+    // Aligned columns, `{{` and `}}` are literal braces
+    logger().set_format("{{{level:^9}}} {thread:>8.8} | {message}");
+    info!("Padded and truncated columns");
+    warn!("Level is centered");
+
+    // Strict parsing points at the offending placeholder
+    match logger().try_set_format("{level} {mesage}") {
+        Ok(_) => info!("Format accepted"),
+        Err(err) => error!("Rejected format: {}", err),
+    }
 }
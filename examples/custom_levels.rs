@@ -0,0 +1,28 @@
+use hackerlog::*;
+use std::sync::LazyLock;
+use termion::color::Rgb;
+
+// Custom levels slot in between the built-in ones by severity (INFO is 30, WARN is 40)
+static VULN: LazyLock<LogLevel> =
+    LazyLock::new(|| LogLevel::register("VULN", "[*]", Rgb(255, 0, 255), 45));
+static PROMPT: LazyLock<LogLevel> =
+    LazyLock::new(|| LogLevel::register("PROMPT", "[?]", Rgb(0, 255, 255), 35));
+static LOOT: LazyLock<LogLevel> =
+    LazyLock::new(|| LogLevel::register("LOOT", "[$]", Rgb(255, 215, 0), 65));
+
+fn main() {
+    logger().min_level(LogLevel::TRACE);
+    trace!("Packet 1: 60 bytes");
+    debug!("Handshake done");
+
+    logger().set_format("{symbol} {level}: {message}");
+    log!(*VULN, "SQL injection in /login");
+    log!(*PROMPT, "Continue with exploitation?");
+    log!(*LOOT, "Dumped 1337 password hashes");
+
+    // Custom levels take part in filtering like any other level
+    logger().min_level(*VULN);
+    log!(*PROMPT, "Hidden");
+    warn!("Hidden too");
+    log!(*LOOT, "Still shown");
+}
@@ -0,0 +1,32 @@
+use hackerlog::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+fn main() {
+    // Providers run for every record and write straight into the line
+    logger()
+        .register_placeholder("target_ip", |_, out| out.push_str("10.10.11.42"))
+        .register_placeholder("request", |_, out| {
+            out.push_str(&REQUESTS.load(Ordering::Relaxed).to_string())
+        })
+        .register_placeholder("location", |record, out| {
+            out.push_str(&format!("{}:{}", record.file(), record.line()))
+        });
+
+    logger().set_format("{symbol} [{target_ip} #{request:>3}] {message} ({location})");
+    for path in ["/login", "/admin", "/backup.zip"] {
+        REQUESTS.fetch_add(1, Ordering::Relaxed);
+        info!("GET {}", path);
+    }
+
+    // Unregistered names are printed as is, strict parsing rejects them
+    logger().set_format("{symbol} {hostname} {message}");
+    warn!("No hostname provider yet");
+    if let Err(err) = FormatTemplate::try_parse("{hostname} {message}") {
+        error!("{}", err);
+    }
+
+    logger().register_placeholder("hostname", |_, out| out.push_str("kali"));
+    success!("Resolved once registered");
+}
@@ -0,0 +1,35 @@
+use hackerlog::*;
+use std::{thread, time::Duration};
+
+fn main() {
+    logger().set_format("{symbol} [{elapsed:>10}] [+{delta:<9}] {message}");
+    info!("Connecting to target");
+    thread::sleep(Duration::from_millis(120));
+    info!("Connected");
+
+    // Measure the exploit itself, not the setup before it
+    logger().reset_elapsed();
+    info!("Sending payload");
+    thread::sleep(Duration::from_millis(35));
+    success!("Got a shell");
+
+    // Raw numbers for post-processing
+    logger().set_format("{elapsed_ms} {delta_us} {message}");
+    info!("Raw milliseconds and microseconds");
+
+    // Per thread deltas ignore records from other threads
+    logger()
+        .delta_scope(DeltaScope::Thread)
+        .set_format("{symbol} {thread:<8} +{delta} {message}");
+    let worker = thread::spawn(|| {
+        for i in 0..3 {
+            info!("Worker step {}", i);
+            thread::sleep(Duration::from_millis(10));
+        }
+    });
+    for i in 0..3 {
+        info!("Main step {}", i);
+        thread::sleep(Duration::from_millis(25));
+    }
+    worker.join().unwrap();
+}
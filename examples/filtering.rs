@@ -1,5 +1,21 @@
 use hackerlog::*;
 
+mod net {
+    use hackerlog::*;
+
+    pub mod parser {
+        use hackerlog::*;
+
+        pub fn parse() {
+            debug!("Parsed packet header");
+        }
+    }
+
+    pub fn connect() {
+        debug!("Connecting");
+    }
+}
+
 fn main() {
     // Set minimum log level to WARN - this will hide DEBUG and INFO messages
     logger().min_level(LogLevel::WARN);
@@ -14,4 +30,15 @@ fn main() {
 
     debug!("Now this debug message shows");
     info!("And this info message too");
+
+    // Per module directives, matched against the caller's module path. The same
+    // syntax is read from the HACKERLOG environment variable at startup, e.g.
+    // HACKERLOG=info,filtering::net::parser=debug cargo run --example filtering
+    logger()
+        .set_filter("info,filtering::net::parser=debug")
+        .expect("valid directives");
+
+    debug!("Hidden again");
+    net::connect(); // Hidden, only the parser module is at DEBUG
+    net::parser::parse(); // Shows
 }
@@ -0,0 +1,25 @@
+use hackerlog::*;
+use std::{fs::File, time::Duration};
+
+fn main() -> std::io::Result<()> {
+    // Flushes whatever is still buffered when main returns
+    let _flush = logger().flush_guard();
+
+    logger().set_writer(Box::new(File::create("flush_policy.log")?))?;
+
+    // Only push the buffer out every 1000 records
+    logger().set_flush_policy(FlushPolicy::EveryN(1000));
+    for i in 0..100_000 {
+        info!("Probe {} sent", i);
+    }
+
+    // Or every 200ms, or whenever something important happens
+    logger().set_flush_policy(FlushPolicy::Interval(Duration::from_millis(200)));
+    info!("Written out within 200ms, even if nothing else gets logged");
+
+    logger().set_flush_policy(FlushPolicy::AtLevel(LogLevel::WARN));
+    info!("Buffered");
+    warn!("Flushed together with the line above");
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use hackerlog::*;
+
+fn main() {
+    logger().min_level(LogLevel::DEBUG);
+
+    let mut leak = vec![0x41u8; 64];
+    leak[..4].copy_from_slice(b"\x7fELF");
+    leak.extend_from_slice(&0x7ffff7a2d830u64.to_le_bytes());
+    leak.extend_from_slice(b"/bin/sh\0");
+
+    // Repeated lines collapse into `*`
+    hexdump!(LogLevel::DEBUG, &leak);
+    hexdump!(LogLevel::INFO, &leak[64..], "Tail of chunk {}", 3);
+
+    // Highlight the libc pointer, start at the address the buffer was read from
+    let dump = Hexdump::new(&leak)
+        .offset(0x7fffffffe3a0)
+        .highlight(64..70)
+        .collapse(false);
+    hexdump!(LogLevel::SUCCESS, dump, "Stack dump");
+
+    // xxd style, 2 byte groups
+    hexdump!(
+        LogLevel::INFO,
+        Hexdump::new(b"GET / HTTP/1.1\r\nHost: x\r\n\r\n").group(2)
+    );
+
+    // Files get the same dump without the highlighting escapes
+    logger().add_sink(Sink::stderr("plain").color(ColorMode::Never));
+    hexdump!(
+        LogLevel::WARN,
+        Hexdump::new(&leak[60..]).highlight(4..10),
+        "Pointer"
+    );
+}
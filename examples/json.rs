@@ -0,0 +1,26 @@
+#[cfg(feature = "structured")]
+use hackerlog::*;
+
+#[cfg(feature = "structured")]
+fn main() {
+    // One JSON object per line, ready for `jq`
+    logger().use_json_format();
+
+    let _ctx = logger().add_context("target", "10.0.0.1");
+    info!("Plain records work too");
+
+    info_event!(
+        "Port open",
+        {
+            "port" => 443,
+            "service" => "https",
+            "banner" => serde_json::json!({ "server": "nginx", "tls": true })
+        }
+    );
+}
+
+#[cfg(not(feature = "structured"))]
+fn main() {
+    println!("This example requires the 'structured' feature. Run with:");
+    println!("    cargo run --example json --features structured");
+}
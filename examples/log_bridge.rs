@@ -0,0 +1,23 @@
+#[cfg(feature = "log")]
+use hackerlog::*;
+
+#[cfg(feature = "log")]
+fn main() {
+    logger()
+        .min_level(LogLevel::DEBUG)
+        .set_format("{symbol} <{target}> {message}");
+    init_log_bridge().expect("another logger was already installed");
+
+    // Records from dependencies that use the `log` facade end up here too
+    log::info!("Hello from the log crate");
+    log::debug!(target: "net::parser", "Parsed {} packets", 42);
+    log::warn!("Connection reset");
+
+    info!("And hackerlog's own macros keep working");
+}
+
+#[cfg(not(feature = "log"))]
+fn main() {
+    println!("This example requires the 'log' feature. Run with:");
+    println!("    cargo run --example log_bridge --features log");
+}
@@ -0,0 +1,26 @@
+use hackerlog::*;
+
+fn main() {
+    // logfmt on stdout, values with spaces, quotes or newlines get quoted and escaped
+    logger().use_logfmt_format();
+
+    let _ctx = logger().add_context("host", "10.0.0.1");
+    info!("Scan started");
+    warn!("Banner: \"SSH-2.0-OpenSSH_8.9\"\nsecond line");
+
+    #[cfg(feature = "structured")]
+    info_event!(
+        "Port open",
+        {
+            "port" => 22,
+            "service" => "ssh",
+            "note" => "key=value pairs inside"
+        }
+    );
+
+    // Or per sink, next to a regular human readable one
+    logger()
+        .set_format("{symbol} {context}{message}")
+        .add_sink(Sink::stderr("shipper").logfmt());
+    success!("Both sinks got this");
+}
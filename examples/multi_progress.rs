@@ -0,0 +1,34 @@
+use hackerlog::*;
+use std::{thread, time::Duration};
+
+fn main() {
+    let bars = MultiProgress::new();
+    let targets = ["10.10.11.42", "10.10.11.43", "10.10.11.44"];
+
+    // Each bar gets its own line below the log output and its own thread
+    thread::scope(|scope| {
+        for (i, target) in targets.iter().enumerate() {
+            let mut scan = bars.add(Progress::with_total(format!("Scanning {}", target), 500));
+            scope.spawn(move || {
+                for port in 1..=500u64 {
+                    thread::sleep(Duration::from_millis(2 + i as u64 * 2));
+                    if port % (97 + i as u64 * 50) == 0 {
+                        success!("{}:{} open", target, port);
+                    }
+                    scan.inc(1);
+                }
+                scan.finish_with_message(format!("Scan of {} done", target));
+            });
+        }
+
+        let mut analysis = bars.add(Progress::new("Analyzing results"));
+        scope.spawn(move || {
+            for _ in 0..20 {
+                thread::sleep(Duration::from_millis(100));
+                analysis.inc(1);
+            }
+            warn!("Analysis stopped early");
+            analysis.finish();
+        });
+    });
+}
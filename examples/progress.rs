@@ -13,25 +13,22 @@ fn main() {
 
     // Progress with total
     let mut progress = Progress::with_total("Downloading", 100);
-    for i in 0..=10 {
+    for i in 1..=10 {
         thread::sleep(Duration::from_millis(100));
         progress.inc(10);
         progress.update(format!("Downloading chunk {}/10", i));
     }
     progress.finish_with_message("Download complete!");
 
-    // Multiple progress indicators
+    // On a terminal the bar is redrawn in place, regular log lines show up above it.
+    // Redirect stdout to a file to get a throttled line per second instead.
     let mut scan = Progress::with_total("Port scan", 1000);
-    let mut analysis = Progress::new("Analyzing results");
-
-    for i in (0..=1000).step_by(100) {
-        thread::sleep(Duration::from_millis(50));
-        scan.inc(100);
-        if i % 200 == 0 {
-            analysis.inc(1);
+    for port in 1..=1000 {
+        thread::sleep(Duration::from_millis(3));
+        if port % 250 == 0 {
+            success!("Port {} open", port);
         }
+        scan.inc(1);
     }
-
     scan.finish();
-    analysis.finish_with_message("Analysis complete - Found 5 open ports");
 }
@@ -0,0 +1,39 @@
+use hackerlog::*;
+use std::{
+    io::{self, Read},
+    thread,
+    time::Duration,
+};
+
+// Stands in for a socket that delivers data in bursts
+struct SlowSource {
+    remaining: usize,
+}
+
+impl Read for SlowSource {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        thread::sleep(Duration::from_millis(2));
+        let n = buf.len().min(self.remaining).min(64 * 1024);
+        buf[..n].fill(0x41);
+        self.remaining -= n;
+        Ok(n)
+    }
+}
+
+fn main() -> io::Result<()> {
+    let size = 8 * 1024 * 1024;
+
+    // Reading through the adapter advances the bar, sizes show up as KiB/MiB/GiB
+    let mut download = Progress::with_total("Downloading firmware.bin", size as u64).bytes();
+    let mut reader = ProgressReader::new(SlowSource { remaining: size }, &mut download);
+    let copied = io::copy(&mut reader, &mut io::sink())?;
+    download.finish_with_message(format!("Downloaded {} bytes", copied));
+
+    // Same for writes, here without a known size
+    let mut upload = Progress::new("Uploading memory dump").bytes();
+    let mut writer = ProgressWriter::new(io::sink(), &mut upload);
+    io::copy(&mut SlowSource { remaining: size / 2 }, &mut writer)?;
+    upload.finish();
+
+    Ok(())
+}
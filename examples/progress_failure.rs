@@ -0,0 +1,46 @@
+use hackerlog::*;
+use std::{io, panic, thread, time::Duration};
+
+fn fetch_wordlist() -> io::Result<()> {
+    let mut download = Progress::with_total("Fetching wordlist", 100);
+    for chunk in 0..10 {
+        thread::sleep(Duration::from_millis(50));
+        if chunk == 6 {
+            // The early return drops the bar, which reports it as abandoned at 60/100
+            return Err(io::Error::new(io::ErrorKind::ConnectionReset, "reset by peer"));
+        }
+        download.inc(10);
+    }
+    download.finish();
+    Ok(())
+}
+
+fn main() {
+    if let Err(err) = fetch_wordlist() {
+        error!("Download failed: {}", err);
+    }
+
+    let mut login = Progress::with_total("Trying credentials", 50);
+    for _ in 0..20 {
+        thread::sleep(Duration::from_millis(20));
+        login.inc(1);
+    }
+    login.fail_with_message("Account locked after 20 attempts");
+
+    // Keep the default panic message out of the way of the bar
+    panic::set_hook(Box::new(|_| {}));
+
+    // A panic inside the scope marks the bar failed and comes back as `Err`
+    let result = Progress::with_total("Parsing responses", 10).scope(|parse| {
+        for i in 0..10 {
+            thread::sleep(Duration::from_millis(50));
+            if i == 4 {
+                panic!("unexpected status code 502");
+            }
+            parse.inc(1);
+        }
+    });
+    if result.is_err() {
+        warn!("Continuing without the parsed responses");
+    }
+}
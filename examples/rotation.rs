@@ -0,0 +1,30 @@
+use hackerlog::*;
+use std::thread;
+
+fn main() -> std::io::Result<()> {
+    // Roll over every 4 KiB and keep the 3 most recent files around
+    logger().set_rotating_file("rotation.log", Rotation::Size(4096), 3)?;
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            thread::spawn(move || {
+                for j in 0..100 {
+                    info!("Worker {} reporting line {}", i, j);
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    // Writers can also be built by hand, e.g. for daily logs with dated suffixes
+    let daily = RotatingFileWriter::new("daily.log", Rotation::Daily)?
+        .suffix(RotationSuffix::Timestamped)
+        .max_files(7);
+    logger().set_writer(Box::new(daily))?;
+    info!("This goes to daily.log");
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use hackerlog::*;
+use std::fs::File;
+
+fn main() -> std::io::Result<()> {
+    logger().min_level(LogLevel::DEBUG);
+
+    // Terse lines on the terminal, only INFO and above
+    logger().add_sink(
+        Sink::stdout("default")
+            .format("{symbol} {message}")
+            .min_level(LogLevel::INFO),
+    );
+
+    // Everything with full details in a file, without color escapes
+    logger().add_sink(
+        Sink::new("file", File::create("sinks.log")?)
+            .format("{datetime} [{level}] <{file}:{line}> {message}")
+            .min_level(LogLevel::DEBUG)
+            .color(ColorMode::Never),
+    );
+
+    debug!("Only in sinks.log");
+    info!("On the terminal and in sinks.log");
+
+    // Sinks can be dropped at runtime
+    logger().remove_sink("file");
+    warn!("Only on the terminal");
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use hackerlog::*;
+use std::{thread, time::Duration};
+
+fn main() {
+    // Keeps spinning while the main thread is blocked
+    let spinner = Progress::spinner("Waiting for reverse shell");
+    thread::sleep(Duration::from_secs(2));
+    spinner.finish_with_message("Connection from 10.10.11.42:51234");
+
+    // ASCII frames, counting attempts along the way
+    let mut brute = Progress::new("Brute forcing PIN").frames(Progress::ASCII);
+    for pin in 0..40 {
+        thread::sleep(Duration::from_millis(50));
+        if pin == 20 {
+            info!("Lockout counter reset");
+        }
+        brute.inc(1);
+    }
+    brute.fail();
+
+    // Any frames work, the symbol slot is replaced by the current one
+    let dots = Progress::new("Leaking canary").frames([".  ", ".. ", "...", " ..", "  .", "   "]);
+    thread::sleep(Duration::from_secs(1));
+    dots.finish();
+}
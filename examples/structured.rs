@@ -1,5 +1,6 @@
 #[cfg(feature = "structured")]
 use hackerlog::*;
+#[cfg(feature = "structured")]
 use std::{thread, time::Duration};
 
 #[cfg(feature = "structured")]
@@ -18,7 +19,7 @@ fn simulate_operation() {
 #[cfg(feature = "structured")]
 fn main() {
     // Enable structured format
-    logger().structured_format();
+    logger().structured_format().min_level(LogLevel::DEBUG);
 
     // Basic structured logging
     info_event!(
@@ -46,6 +47,19 @@ fn main() {
         }
     );
 
+    // Place fields in the template, `{fields}` collects the ones left over
+    logger().set_format("{symbol} {field:host}:{field:port|?} {message} {fields}");
+    info_event!(
+        "Port open",
+        {
+            "host" => "10.10.11.42",
+            "port" => 445,
+            "service" => "smb"
+        }
+    );
+    info_event!("Host up", { "host" => "10.10.11.43" });
+    logger().structured_format();
+
     // Automatic timing of operations
     {
         let _timer = TimedOperation::new("database_query", LogLevel::DEBUG);
@@ -0,0 +1,24 @@
+use hackerlog::*;
+
+fn main() {
+    // Every placeholder of a record sees the same instant
+    logger().set_format("{symbol} {datetime:%Y-%m-%dT%H:%M:%S%.6f%:z} {message}");
+    info!("Microseconds with offset, lines up with pcap timestamps");
+
+    logger().set_format("{symbol} [{epoch}] [{epoch_ms}] {message}");
+    info!("Unix epoch in seconds and milliseconds");
+
+    logger().set_format("{symbol} {utc} {message}");
+    info!("RFC 3339 in UTC");
+
+    // Switch the whole logger to UTC
+    logger()
+        .timezone(Timezone::Utc)
+        .set_format("{symbol} {date} {time:%H:%M:%S%.3f} {message}");
+    info!("Rendered in UTC");
+
+    match FormatTemplate::try_parse("{time:%H:%Q} {message}") {
+        Ok(_) => info!("Accepted"),
+        Err(err) => error!("{}", err),
+    }
+}
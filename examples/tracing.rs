@@ -0,0 +1,25 @@
+#[cfg(feature = "tracing")]
+use hackerlog::*;
+
+#[cfg(feature = "tracing")]
+fn main() {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    logger().min_level(LogLevel::DEBUG);
+    let subscriber = tracing_subscriber::registry().with(HackerlogLayer::new());
+    tracing::subscriber::set_global_default(subscriber).expect("subscriber already set");
+
+    let span = tracing::info_span!("scan", target_host = "10.0.0.1");
+    let _enter = span.enter();
+
+    // Span fields end up in {context}, event fields next to the message
+    tracing::info!("Starting scan");
+    tracing::debug!(port = 22, state = "open", "Probed port");
+    tracing::warn!(retries = 3, "Host is slow to respond");
+}
+
+#[cfg(not(feature = "tracing"))]
+fn main() {
+    println!("This example requires the 'tracing' feature. Run with:");
+    println!("    cargo run --example tracing --features tracing");
+}
@@ -17,4 +17,13 @@ fn main() {
     error!("This is an error message");
     success!("This is a success message");
     failure!("This is a failure message");
-}
\ No newline at end of file
+
+    // Verbose mode has its own layout slot, so regular formats come back untouched
+    logger().set_format("{symbol} {message}");
+    logger().verbose(false);
+    info!("Back to the regular format");
+
+    logger().set_verbose_format("{symbol} [{datetime}] {thread}@{file}:{line} {message}");
+    logger().verbose(true);
+    info!("Custom verbose layout");
+}
@@ -0,0 +1,223 @@
+use crate::sink::SharedWriter;
+use std::{
+    collections::VecDeque,
+    io,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Condvar, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+// Drop reports are rate limited, so they don't add to the flood that caused them
+const REPORT_INTERVAL: Duration = Duration::from_secs(1);
+
+// What happens to a record when the async queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    // Wait until the writer thread made room
+    #[default]
+    Block,
+    // Discard the record that didn't fit
+    DropNewest,
+    // Discard the oldest queued record to make room
+    DropOldest,
+}
+
+// A rendered line for one sink
+pub(crate) struct Job {
+    pub(crate) writer: SharedWriter,
+    pub(crate) bytes: Vec<u8>,
+}
+
+// The lines of one record for every sink that accepted it
+pub(crate) struct QueuedRecord {
+    pub(crate) jobs: Vec<Job>,
+    // Decided by the flush policy when the record was queued
+    pub(crate) flush: bool,
+}
+
+#[derive(Default)]
+struct State {
+    records: VecDeque<QueuedRecord>,
+    busy: bool,
+    shutdown: bool,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    idle: Condvar,
+    capacity: usize,
+    policy: OverflowPolicy,
+    dropped: AtomicU64,
+    unreported: AtomicU64,
+    last_report: Mutex<Instant>,
+}
+
+// Bounded queue drained by a dedicated writer thread
+pub(crate) struct Background {
+    shared: Arc<Shared>,
+    thread: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl Background {
+    pub(crate) fn start(capacity: usize, policy: OverflowPolicy) -> io::Result<Self> {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State::default()),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            idle: Condvar::new(),
+            capacity: capacity.max(1),
+            policy,
+            dropped: AtomicU64::new(0),
+            unreported: AtomicU64::new(0),
+            last_report: Mutex::new(Instant::now()),
+        });
+
+        let worker = Arc::clone(&shared);
+        let thread = thread::Builder::new()
+            .name("hackerlog-writer".into())
+            .spawn(move || worker.run())?;
+
+        Ok(Self {
+            shared,
+            thread: Mutex::new(Some(thread)),
+        })
+    }
+
+    pub(crate) fn push(&self, record: QueuedRecord) {
+        if record.jobs.is_empty() {
+            return;
+        }
+
+        let shared = &self.shared;
+        let mut state = shared.state.lock().unwrap();
+        if state.records.len() >= shared.capacity {
+            match shared.policy {
+                OverflowPolicy::Block => {
+                    while state.records.len() >= shared.capacity && !state.shutdown {
+                        state = shared.not_full.wait(state).unwrap();
+                    }
+                }
+                OverflowPolicy::DropNewest => {
+                    shared.record_drop();
+                    return;
+                }
+                OverflowPolicy::DropOldest => {
+                    state.records.pop_front();
+                    shared.record_drop();
+                }
+            }
+        }
+
+        // The writer thread may already have drained the queue and exited
+        if state.shutdown {
+            drop(state);
+            write_batch(VecDeque::from([record]));
+            return;
+        }
+
+        state.records.push_back(record);
+        shared.not_empty.notify_one();
+    }
+
+    // Blocks until everything queued so far has been written
+    pub(crate) fn wait_idle(&self) {
+        let shared = &self.shared;
+        let mut state = shared.state.lock().unwrap();
+        while !state.records.is_empty() || state.busy {
+            state = shared.idle.wait(state).unwrap();
+        }
+    }
+
+    pub(crate) fn dropped(&self) -> u64 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+
+    // Drops counted since the last report, at most once per `REPORT_INTERVAL` unless forced
+    pub(crate) fn take_unreported(&self, force: bool) -> u64 {
+        if self.shared.unreported.load(Ordering::Relaxed) == 0 {
+            return 0;
+        }
+
+        let mut last_report = self.shared.last_report.lock().unwrap();
+        if !force && last_report.elapsed() < REPORT_INTERVAL {
+            return 0;
+        }
+        *last_report = Instant::now();
+        self.shared.unreported.swap(0, Ordering::Relaxed)
+    }
+
+    // Drains the queue and stops the writer thread
+    pub(crate) fn shutdown(&self) {
+        {
+            let mut state = self.shared.state.lock().unwrap();
+            state.shutdown = true;
+            self.shared.not_empty.notify_all();
+            self.shared.not_full.notify_all();
+        }
+
+        if let Some(thread) = self.thread.lock().unwrap().take() {
+            thread.join().ok();
+        }
+    }
+}
+
+impl Shared {
+    fn record_drop(&self) {
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+        self.unreported.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn run(&self) {
+        loop {
+            let batch = {
+                let mut state = self.state.lock().unwrap();
+                while state.records.is_empty() && !state.shutdown {
+                    state = self.not_empty.wait(state).unwrap();
+                }
+                if state.records.is_empty() {
+                    break;
+                }
+                state.busy = true;
+                self.not_full.notify_all();
+                std::mem::take(&mut state.records)
+            };
+
+            write_batch(batch);
+
+            let mut state = self.state.lock().unwrap();
+            state.busy = false;
+            if state.records.is_empty() {
+                self.idle.notify_all();
+            }
+        }
+
+        self.idle.notify_all();
+    }
+}
+
+// Writes every queued line, then flushes each writer once if any record asked for it
+fn write_batch(batch: VecDeque<QueuedRecord>) {
+    let mut touched: Vec<SharedWriter> = Vec::new();
+    let mut flush = false;
+
+    for record in batch {
+        flush |= record.flush;
+        for job in record.jobs {
+            job.writer.lock().unwrap().write_line(&job.bytes).ok();
+            if !touched.iter().any(|writer| Arc::ptr_eq(writer, &job.writer)) {
+                touched.push(job.writer);
+            }
+        }
+    }
+
+    if flush {
+        for writer in touched {
+            writer.lock().unwrap().flush().ok();
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use chrono::{DateTime, FixedOffset, Local, Utc};
+use std::{
+    cell::Cell,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+// Timezone the clock placeholders are rendered in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Timezone {
+    #[default]
+    Local,
+    Utc,
+}
+
+impl Timezone {
+    pub(crate) const fn as_u8(self) -> u8 {
+        match self {
+            Self::Local => 0,
+            Self::Utc => 1,
+        }
+    }
+
+    pub(crate) const fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::Utc,
+            _ => Self::Local,
+        }
+    }
+}
+
+// What `{delta}` measures against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeltaScope {
+    // Previous record of any thread, i.e. the line above in the output
+    #[default]
+    Global,
+    // Previous record of the logging thread
+    Thread,
+}
+
+impl DeltaScope {
+    pub(crate) const fn as_u8(self) -> u8 {
+        match self {
+            Self::Global => 0,
+            Self::Thread => 1,
+        }
+    }
+
+    pub(crate) const fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::Thread,
+            _ => Self::Global,
+        }
+    }
+}
+
+thread_local! {
+    static LAST_RECORD: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+// Time of a record, sampled once when it is created
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Clock {
+    pub(crate) time: DateTime<FixedOffset>,
+    pub(crate) elapsed: Duration,
+    pub(crate) delta: Duration,
+}
+
+pub(crate) struct ClockState {
+    timezone: AtomicU8,
+    scope: AtomicU8,
+    // Monotonic reference points, wall clock changes don't affect `{elapsed}` or `{delta}`
+    start: Mutex<Instant>,
+    last_record: Mutex<Option<Instant>>,
+}
+
+impl Default for ClockState {
+    fn default() -> Self {
+        Self {
+            timezone: AtomicU8::new(Timezone::Local.as_u8()),
+            scope: AtomicU8::new(DeltaScope::Global.as_u8()),
+            start: Mutex::new(Instant::now()),
+            last_record: Mutex::new(None),
+        }
+    }
+}
+
+impl ClockState {
+    pub(crate) fn set_timezone(&self, timezone: Timezone) {
+        self.timezone.store(timezone.as_u8(), Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_delta_scope(&self, scope: DeltaScope) {
+        self.scope.store(scope.as_u8(), Ordering::Relaxed);
+    }
+
+    // Restarts `{elapsed}`, the next `{delta}` is measured from here as well
+    pub(crate) fn reset(&self) {
+        *self.start.lock().unwrap() = Instant::now();
+    }
+
+    pub(crate) fn sample(&self) -> Clock {
+        let now = Utc::now();
+        let time = match Timezone::from_u8(self.timezone.load(Ordering::Relaxed)) {
+            Timezone::Local => now.with_timezone(&Local).fixed_offset(),
+            Timezone::Utc => now.fixed_offset(),
+        };
+
+        let start = *self.start.lock().unwrap();
+        let instant = Instant::now();
+        let last = match DeltaScope::from_u8(self.scope.load(Ordering::Relaxed)) {
+            DeltaScope::Global => self.last_record.lock().unwrap().replace(instant),
+            DeltaScope::Thread => LAST_RECORD.with(|last| last.replace(Some(instant))),
+        };
+        // Records from before a reset count from the reset
+        let previous = last.map_or(start, |last| last.max(start));
+
+        Clock {
+            time,
+            elapsed: instant.saturating_duration_since(start),
+            delta: instant.saturating_duration_since(previous),
+        }
+    }
+}
+
+// Compact human readable form, e.g. `850us`, `12.345ms`, `1.234s` or `2m03.456s`
+pub(crate) fn format_duration(duration: Duration, output: &mut String) {
+    let micros = duration.as_micros();
+    let formatted = if micros < 1_000 {
+        format!("{}us", micros)
+    } else if micros < 1_000_000 {
+        format!("{}.{:03}ms", micros / 1_000, micros % 1_000)
+    } else if micros < 60_000_000 {
+        format!("{}.{:03}s", micros / 1_000_000, micros / 1_000 % 1_000)
+    } else {
+        let millis = micros / 1_000;
+        format!(
+            "{}m{:02}.{:03}s",
+            millis / 60_000,
+            millis / 1_000 % 60,
+            millis % 1_000
+        )
+    };
+    output.push_str(&formatted);
+}
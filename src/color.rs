@@ -0,0 +1,59 @@
+use std::{env, sync::OnceLock};
+
+// Whether records get wrapped in ANSI color escapes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    Always,
+    Never,
+    // Color terminals only, honoring NO_COLOR and CLICOLOR_FORCE
+    #[default]
+    Auto,
+}
+
+impl ColorMode {
+    pub(crate) const fn as_u8(self) -> u8 {
+        match self {
+            Self::Always => 0,
+            Self::Never => 1,
+            Self::Auto => 2,
+        }
+    }
+
+    pub(crate) const fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Always,
+            1 => Self::Never,
+            _ => Self::Auto,
+        }
+    }
+
+    pub(crate) fn enabled(self, is_terminal: bool) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto => match env_override() {
+                Some(forced) => forced,
+                None => is_terminal,
+            },
+        }
+    }
+}
+
+// See https://no-color.org and https://bixense.com/clicolors, NO_COLOR wins if both are set
+fn env_override() -> Option<bool> {
+    static OVERRIDE: OnceLock<Option<bool>> = OnceLock::new();
+
+    *OVERRIDE.get_or_init(|| {
+        let set = |name| env::var_os(name).is_some_and(|value| !value.is_empty());
+        let force =
+            env::var_os("CLICOLOR_FORCE").is_some_and(|value| !value.is_empty() && value != "0");
+
+        if set("NO_COLOR") {
+            Some(false)
+        } else if force {
+            Some(true)
+        } else {
+            None
+        }
+    })
+}
@@ -0,0 +1,143 @@
+use std::{
+    cell::RefCell,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+struct ContextEntry {
+    id: u64,
+    key: String,
+    value: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scope {
+    Thread,
+    Global,
+}
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+static GLOBAL: Mutex<Vec<ContextEntry>> = Mutex::new(Vec::new());
+
+thread_local! {
+    static THREAD: RefCell<Vec<ContextEntry>> = const { RefCell::new(Vec::new()) };
+}
+
+// Context guard for automatic cleanup. It removes exactly the entry it added,
+// no matter in which order guards are dropped. Thread context lives in the
+// creating thread, so the guard can't be sent elsewhere.
+#[must_use = "the context entry is removed again when the guard is dropped"]
+pub struct ContextGuard {
+    id: u64,
+    scope: Scope,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        let remove = |entries: &mut Vec<ContextEntry>| entries.retain(|entry| entry.id != self.id);
+        match self.scope {
+            Scope::Thread => {
+                // The thread local may already be gone when a guard outlives it during thread exit
+                let _ = THREAD.try_with(|entries| remove(&mut entries.borrow_mut()));
+            }
+            Scope::Global => remove(&mut GLOBAL.lock().unwrap()),
+        }
+    }
+}
+
+fn push(scope: Scope, key: String, value: String) -> ContextGuard {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    let entry = ContextEntry { id, key, value };
+    match scope {
+        Scope::Thread => THREAD.with(|entries| entries.borrow_mut().push(entry)),
+        Scope::Global => GLOBAL.lock().unwrap().push(entry),
+    }
+
+    ContextGuard {
+        id,
+        scope,
+        _not_send: PhantomData,
+    }
+}
+
+pub(crate) fn push_thread(key: String, value: String) -> ContextGuard {
+    push(Scope::Thread, key, value)
+}
+
+pub(crate) fn push_global(key: String, value: String) -> ContextGuard {
+    push(Scope::Global, key, value)
+}
+
+// Global entries first, then the ones of the calling thread, each in insertion order
+pub(crate) fn capture() -> Vec<(String, String)> {
+    let pair = |entry: &ContextEntry| (entry.key.clone(), entry.value.clone());
+
+    let mut context: Vec<(String, String)> = GLOBAL.lock().unwrap().iter().map(pair).collect();
+    THREAD.with(|entries| context.extend(entries.borrow().iter().map(pair)));
+    context
+}
+
+// The calling thread's context, frozen so it can be re-installed in another thread
+#[derive(Debug, Clone, Default)]
+pub struct ContextSnapshot {
+    entries: Vec<(String, String)>,
+}
+
+impl ContextSnapshot {
+    // Global context is visible everywhere already, so only the thread context is captured
+    pub fn capture() -> Self {
+        let entries = THREAD.with(|entries| {
+            entries
+                .borrow()
+                .iter()
+                .map(|entry| (entry.key.clone(), entry.value.clone()))
+                .collect()
+        });
+        Self { entries }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Adds the captured entries to the calling thread until the guard is dropped
+    pub fn enter(&self) -> SnapshotGuard {
+        SnapshotGuard {
+            _guards: self
+                .entries
+                .iter()
+                .map(|(key, value)| push_thread(key.clone(), value.clone()))
+                .collect(),
+        }
+    }
+
+    // Wraps `f` so it runs with this context, e.g. for `thread::Builder` or `thread::scope`
+    pub fn wrap<F, T>(self, f: F) -> impl FnOnce() -> T
+    where
+        F: FnOnce() -> T,
+    {
+        move || {
+            let _guard = self.enter();
+            f()
+        }
+    }
+}
+
+#[must_use = "the snapshot is removed again when the guard is dropped"]
+pub struct SnapshotGuard {
+    _guards: Vec<ContextGuard>,
+}
+
+// Like `thread::spawn`, but the new thread starts with the caller's context
+pub fn spawn_with_context<F, T>(f: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    thread::spawn(ContextSnapshot::capture().wrap(f))
+}
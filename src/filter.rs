@@ -0,0 +1,67 @@
+use crate::{levels::LogLevel, LogError};
+
+// Environment variable read when the global logger is created
+pub const FILTER_ENV: &str = "HACKERLOG";
+
+// Per module level overrides parsed from directives like `info,myapp::net=debug,noisy_dep=warn`
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Filter {
+    // Longest module path first, so the most specific directive wins
+    directives: Vec<(String, LogLevel)>,
+}
+
+impl Filter {
+    // Returns the bare default level, if any, alongside the module directives
+    pub(crate) fn parse(spec: &str) -> Result<(Option<LogLevel>, Self), LogError> {
+        let mut default = None;
+        let mut directives: Vec<(String, LogLevel)> = Vec::new();
+
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((module, level)) => {
+                    let module = module.trim();
+                    if module.is_empty() {
+                        return Err(LogError::new(format!(
+                            "missing module path in directive '{}'",
+                            directive
+                        )));
+                    }
+                    directives.retain(|(existing, _)| existing != module);
+                    directives.push((module.to_string(), level.parse()?));
+                }
+                None => match directive.parse() {
+                    Ok(level) => default = Some(level),
+                    // A bare module path enables everything for that module
+                    Err(_) => {
+                        directives.retain(|(existing, _)| existing != directive);
+                        directives.push((directive.to_string(), LogLevel::TRACE));
+                    }
+                },
+            }
+        }
+
+        directives.sort_by_key(|(module, _)| std::cmp::Reverse(module.len()));
+        Ok((default, Self { directives }))
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.directives.is_empty()
+    }
+
+    pub(crate) fn level_for(&self, target: &str) -> Option<LogLevel> {
+        self.directives
+            .iter()
+            .find(|(module, _)| {
+                target
+                    .strip_prefix(module.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .map(|(_, level)| *level)
+    }
+
+    // Most verbose level any directive lets through
+    #[cfg(feature = "log")]
+    pub(crate) fn lowest_level(&self) -> Option<LogLevel> {
+        self.directives.iter().map(|(_, level)| *level).min()
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{levels::LogLevel, sink::Sink};
+use std::{
+    sync::{
+        atomic::{AtomicU32, AtomicU64, Ordering},
+        Arc, Mutex, RwLock, Weak,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+// When buffered sink output gets pushed to the underlying writer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FlushPolicy {
+    #[default]
+    EveryRecord,
+    // After this many records
+    EveryN(u32),
+    // Every interval from a timer thread, so a line logged right before the
+    // program goes quiet doesn't sit in the buffer
+    Interval(Duration),
+    // Only for records at or above this level, e.g. WARN
+    AtLevel(LogLevel),
+}
+
+pub(crate) struct FlushState {
+    policy: RwLock<FlushPolicy>,
+    pending: AtomicU32,
+    // Shared with the timer thread of `FlushPolicy::Interval`
+    last_flush: Arc<Mutex<Instant>>,
+    // Bumped on every policy change, a timer thread stops once it's outdated
+    generation: Arc<AtomicU64>,
+}
+
+impl Default for FlushState {
+    fn default() -> Self {
+        Self {
+            policy: RwLock::new(FlushPolicy::default()),
+            pending: AtomicU32::new(0),
+            last_flush: Arc::new(Mutex::new(Instant::now())),
+            generation: Arc::new(AtomicU64::new(0)),
+        }
+    }
+}
+
+impl FlushState {
+    // `sinks` is only needed by `FlushPolicy::Interval`, which flushes them from a timer thread
+    pub(crate) fn set_policy(&self, policy: FlushPolicy, sinks: Weak<RwLock<Vec<Sink>>>) {
+        *self.policy.write().unwrap() = policy;
+        self.pending.store(0, Ordering::Relaxed);
+        *self.last_flush.lock().unwrap() = Instant::now();
+        let generation = self.generation.fetch_add(1, Ordering::Relaxed) + 1;
+
+        if let FlushPolicy::Interval(interval) = policy {
+            self.start_timer(interval, generation, sinks);
+        }
+    }
+
+    // Without the thread `should_flush` still flushes once records come in after the interval
+    fn start_timer(&self, interval: Duration, generation: u64, sinks: Weak<RwLock<Vec<Sink>>>) {
+        let current = Arc::clone(&self.generation);
+        let last_flush = Arc::clone(&self.last_flush);
+
+        thread::Builder::new()
+            .name("hackerlog-flusher".into())
+            .spawn(move || loop {
+                thread::sleep(interval);
+                // Replaced by another policy, or the logger is gone
+                if current.load(Ordering::Relaxed) != generation {
+                    break;
+                }
+                let Some(sinks) = sinks.upgrade() else {
+                    break;
+                };
+
+                *last_flush.lock().unwrap() = Instant::now();
+                for sink in sinks.read().unwrap().iter() {
+                    sink.flush().ok();
+                }
+            })
+            .ok();
+    }
+
+    pub(crate) fn policy(&self) -> FlushPolicy {
+        *self.policy.read().unwrap()
+    }
+
+    // Called once per written record
+    pub(crate) fn should_flush(&self, level: LogLevel) -> bool {
+        match self.policy() {
+            FlushPolicy::EveryRecord => true,
+            FlushPolicy::EveryN(n) => {
+                let pending = self.pending.fetch_add(1, Ordering::Relaxed) + 1;
+                if pending >= n {
+                    self.pending.store(0, Ordering::Relaxed);
+                    true
+                } else {
+                    false
+                }
+            }
+            // Normally the timer flushes, this bounds the delay on a busy logger as well
+            FlushPolicy::Interval(interval) => {
+                let mut last_flush = self.last_flush.lock().unwrap();
+                if last_flush.elapsed() >= interval {
+                    *last_flush = Instant::now();
+                    true
+                } else {
+                    false
+                }
+            }
+            FlushPolicy::AtLevel(min_level) => level >= min_level,
+        }
+    }
+}
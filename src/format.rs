@@ -1,3 +1,19 @@
+use crate::{
+    clock,
+    record::{self, Record},
+};
+use chrono::{
+    format::{Item, StrftimeItems},
+    SecondsFormat, Utc,
+};
+#[cfg(feature = "structured")]
+use serde_json::Value;
+use std::{
+    fmt, process,
+    sync::{Arc, RwLock},
+    thread,
+};
+
 #[derive(Debug, Clone)]
 pub enum FormatPlaceholder {
     Level,
@@ -6,18 +22,195 @@ pub enum FormatPlaceholder {
     Time,
     Date,
     DateTime,
+    // strftime pattern given after the colon, e.g. `{datetime:%Y-%m-%dT%H:%M:%S%.6f%:z}`
+    Timestamp(String),
+    // RFC 3339 in UTC with microseconds, regardless of the logger timezone
+    Utc,
+    Epoch,
+    EpochMs,
+    // Monotonic time since logger start or `reset_elapsed`, human readable or raw numbers
+    Elapsed,
+    ElapsedMs,
+    ElapsedUs,
+    // Monotonic time since the previous record
+    Delta,
+    DeltaMs,
+    DeltaUs,
+    // A single structured field, with a fallback for records that don't carry it
+    Field {
+        name: String,
+        default: Option<String>,
+    },
+    // Every structured field not placed by a `{field:..}` placeholder
+    Fields,
     ThreadName,
     ThreadId,
     ProcessId,
     File,
     Line,
+    Target,
     Context,
+    // Resolved through the placeholder registry when a record is rendered
+    Custom(String),
     Text(String),
 }
 
+type PlaceholderFn = Arc<dyn Fn(&Record, &mut String) + Send + Sync>;
+
+static PLACEHOLDERS: RwLock<Vec<(String, PlaceholderFn)>> = RwLock::new(Vec::new());
+
+// Registering a name again replaces its provider. Built-in names always win.
+pub(crate) fn register_placeholder(name: &str, provider: PlaceholderFn) {
+    let mut placeholders = PLACEHOLDERS.write().unwrap();
+    match placeholders
+        .iter_mut()
+        .find(|(existing, _)| existing == name)
+    {
+        Some((_, existing)) => *existing = provider,
+        None => placeholders.push((name.to_string(), provider)),
+    }
+}
+
+fn registered_placeholder(name: &str) -> Option<PlaceholderFn> {
+    let placeholders = PLACEHOLDERS.read().unwrap();
+    placeholders
+        .iter()
+        .find(|(existing, _)| existing == name)
+        .map(|(_, provider)| Arc::clone(provider))
+}
+
+// Per placeholder layout, `{level:<7}` or `{thread:>12.12}`, following the
+// `[[fill]align][width][.precision]` syntax of `std::fmt`. Precision truncates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatSpec {
+    pub fill: char,
+    pub align: Align,
+    pub width: Option<usize>,
+    pub precision: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+    #[default]
+    Left,
+    Right,
+    Center,
+}
+
+impl Default for FormatSpec {
+    fn default() -> Self {
+        Self {
+            fill: ' ',
+            align: Align::default(),
+            width: None,
+            precision: None,
+        }
+    }
+}
+
+impl FormatSpec {
+    fn parse(spec: &str) -> Option<Self> {
+        let mut result = Self::default();
+        let align = |c| match c {
+            '<' => Some(Align::Left),
+            '>' => Some(Align::Right),
+            '^' => Some(Align::Center),
+            _ => None,
+        };
+
+        let mut rest = spec;
+        let mut chars = spec.chars();
+        let first = chars.next();
+        let second = chars.next();
+        if let (Some(fill), Some(a)) = (first, second.and_then(align)) {
+            result.fill = fill;
+            result.align = a;
+            rest = &spec[fill.len_utf8() + 1..];
+        } else if let Some(a) = first.and_then(align) {
+            result.align = a;
+            rest = &spec[1..];
+        }
+
+        let (width, precision) = match rest.split_once('.') {
+            Some((width, precision)) => (width, Some(precision)),
+            None => (rest, None),
+        };
+        if !width.is_empty() {
+            result.width = Some(width.parse().ok()?);
+        }
+        if let Some(precision) = precision {
+            result.precision = Some(precision.parse().ok()?);
+        }
+
+        Some(result)
+    }
+
+    fn apply(&self, value: &str, output: &mut String) {
+        let value = match self.precision {
+            Some(precision) => match value.char_indices().nth(precision) {
+                Some((end, _)) => &value[..end],
+                None => value,
+            },
+            None => value,
+        };
+
+        let padding = self
+            .width
+            .map_or(0, |width| width.saturating_sub(value.chars().count()));
+        let (before, after) = match self.align {
+            Align::Left => (0, padding),
+            Align::Right => (padding, 0),
+            Align::Center => (padding / 2, padding - padding / 2),
+        };
+
+        output.extend(std::iter::repeat_n(self.fill, before));
+        output.push_str(value);
+        output.extend(std::iter::repeat_n(self.fill, after));
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatErrorKind {
+    UnknownPlaceholder(String),
+    InvalidSpec(String),
+    InvalidTimeFormat(String),
+    MissingFieldName,
+    UnclosedBrace,
+    UnmatchedClosingBrace,
+}
+
+// Returned by `FormatTemplate::try_parse`, `position` is the byte offset into the template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatError {
+    pub position: usize,
+    pub kind: FormatErrorKind,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            FormatErrorKind::UnknownPlaceholder(name) => {
+                write!(f, "unknown placeholder '{{{}}}'", name)?
+            }
+            FormatErrorKind::InvalidSpec(spec) => write!(f, "invalid format spec '{}'", spec)?,
+            FormatErrorKind::InvalidTimeFormat(pattern) => {
+                write!(f, "invalid time format '{}'", pattern)?
+            }
+            FormatErrorKind::MissingFieldName => write!(f, "missing field name in '{{field:..}}'")?,
+            FormatErrorKind::UnclosedBrace => write!(f, "unclosed '{{'")?,
+            FormatErrorKind::UnmatchedClosingBrace => {
+                write!(f, "unmatched '}}', use '}}}}' for a literal brace")?
+            }
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for FormatError {}
+
 #[derive(Debug, Clone, Default)]
 pub struct FormatTemplate {
-    pub(crate) parts: Vec<FormatPlaceholder>,
+    pub(crate) parts: Vec<(FormatPlaceholder, Option<FormatSpec>)>,
 }
 
 impl FormatTemplate {
@@ -25,52 +218,515 @@ impl FormatTemplate {
         Self { parts: Vec::new() }
     }
 
+    // Lenient parsing, anything that doesn't parse is kept as literal text
     pub fn parse(template: &str) -> Self {
+        Self::parse_with(template, false).unwrap_or_default()
+    }
+
+    // Strict parsing, reports unknown placeholders, bad specs and stray braces.
+    // Custom placeholders have to be registered before the template is parsed.
+    // In both modes `{{` and `}}` produce literal braces.
+    pub fn try_parse(template: &str) -> Result<Self, FormatError> {
+        Self::parse_with(template, true)
+    }
+
+    fn parse_with(template: &str, strict: bool) -> Result<Self, FormatError> {
         let mut parts = Vec::new();
+        let mut text = String::new();
         let mut current = 0;
 
-        while let Some(start) = template[current..].find('{') {
-            let abs_start = current + start;
-
-            // Add preceding text if any
-            if abs_start > current {
-                parts.push(FormatPlaceholder::Text(
-                    template[current..abs_start].to_string(),
-                ));
-            }
-
-            // Find closing brace
-            if let Some(end) = template[abs_start..].find('}') {
-                let placeholder = &template[abs_start + 1..abs_start + end];
-                let part = match placeholder {
-                    "level" => FormatPlaceholder::Level,
-                    "symbol" => FormatPlaceholder::Symbol,
-                    "message" => FormatPlaceholder::Message,
-                    "time" => FormatPlaceholder::Time,
-                    "date" => FormatPlaceholder::Date,
-                    "datetime" => FormatPlaceholder::DateTime,
-                    "thread" => FormatPlaceholder::ThreadName,
-                    "thread_id" => FormatPlaceholder::ThreadId,
-                    "pid" => FormatPlaceholder::ProcessId,
-                    "file" => FormatPlaceholder::File,
-                    "line" => FormatPlaceholder::Line,
-                    "context" => FormatPlaceholder::Context,
-                    _ => FormatPlaceholder::Text(format!("{{{}}}", placeholder)),
-                };
-                parts.push(part);
-                current = abs_start + end + 1;
-            } else {
+        while let Some(offset) = template[current..].find(['{', '}']) {
+            let position = current + offset;
+            text.push_str(&template[current..position]);
+            let rest = &template[position..];
+
+            // Escaped brace
+            if rest.starts_with("{{") || rest.starts_with("}}") {
+                text.push_str(&rest[..1]);
+                current = position + 2;
+                continue;
+            }
+
+            if rest.starts_with('}') {
+                if strict {
+                    return Err(FormatError {
+                        position,
+                        kind: FormatErrorKind::UnmatchedClosingBrace,
+                    });
+                }
+                text.push('}');
+                current = position + 1;
+                continue;
+            }
+
+            let Some(end) = rest.find('}') else {
+                if strict {
+                    return Err(FormatError {
+                        position,
+                        kind: FormatErrorKind::UnclosedBrace,
+                    });
+                }
                 // No closing brace found, treat rest as text
-                parts.push(FormatPlaceholder::Text(template[abs_start..].to_string()));
+                text.push_str(rest);
+                current = template.len();
                 break;
+            };
+
+            let content = &rest[1..end];
+            current = position + end + 1;
+            match parse_placeholder(content, strict) {
+                Ok(part) => {
+                    if !text.is_empty() {
+                        parts.push((FormatPlaceholder::Text(std::mem::take(&mut text)), None));
+                    }
+                    parts.push(part);
+                }
+                Err(kind) if strict => return Err(FormatError { position, kind }),
+                Err(_) => text.push_str(&rest[..=end]),
             }
         }
 
         // Add remaining text
-        if current < template.len() {
-            parts.push(FormatPlaceholder::Text(template[current..].to_string()));
+        text.push_str(&template[current..]);
+        if !text.is_empty() {
+            parts.push((FormatPlaceholder::Text(text), None));
+        }
+
+        Ok(Self { parts })
+    }
+
+    pub(crate) fn render(&self, record: &Record) -> String {
+        let mut output = String::new();
+
+        for (placeholder, spec) in &self.parts {
+            match spec {
+                Some(spec) => {
+                    let mut value = String::new();
+                    self.render_placeholder(placeholder, record, &mut value);
+                    spec.apply(&value, &mut output);
+                }
+                None => self.render_placeholder(placeholder, record, &mut output),
+            }
         }
 
-        Self { parts }
+        output
+    }
+
+    fn render_placeholder(
+        &self,
+        placeholder: &FormatPlaceholder,
+        record: &Record,
+        output: &mut String,
+    ) {
+        match placeholder {
+            FormatPlaceholder::Level => {
+                output.push_str(record.level.name());
+            }
+            FormatPlaceholder::Symbol => {
+                output.push_str(record.level.symbol());
+            }
+            FormatPlaceholder::Message => {
+                output.push_str(record.message);
+
+                // Fields go to `{fields}` if the template has it, otherwise they trail the message
+                #[cfg(feature = "structured")]
+                if !self.has_fields_block() {
+                    let mut block = String::new();
+                    self.push_remaining_fields(record, &mut block);
+                    if !block.is_empty() {
+                        output.push(' ');
+                        output.push_str(&block);
+                    }
+                }
+            }
+            FormatPlaceholder::Time => {
+                output.push_str(&record.clock.time.format("%H:%M:%S").to_string());
+            }
+            FormatPlaceholder::Date => {
+                output.push_str(&record.clock.time.format("%Y-%m-%d").to_string());
+            }
+            FormatPlaceholder::DateTime => {
+                output.push_str(&record.clock.time.format("%Y-%m-%d %H:%M:%S").to_string());
+            }
+            FormatPlaceholder::Timestamp(pattern) => {
+                output.push_str(&record.clock.time.format(pattern).to_string());
+            }
+            FormatPlaceholder::Utc => {
+                let utc = record.clock.time.with_timezone(&Utc);
+                output.push_str(&utc.to_rfc3339_opts(SecondsFormat::Micros, true));
+            }
+            FormatPlaceholder::Epoch => {
+                output.push_str(&record.clock.time.timestamp().to_string());
+            }
+            FormatPlaceholder::EpochMs => {
+                output.push_str(&record.clock.time.timestamp_millis().to_string());
+            }
+            FormatPlaceholder::Elapsed => clock::format_duration(record.clock.elapsed, output),
+            FormatPlaceholder::ElapsedMs => {
+                output.push_str(&record.clock.elapsed.as_millis().to_string());
+            }
+            FormatPlaceholder::ElapsedUs => {
+                output.push_str(&record.clock.elapsed.as_micros().to_string());
+            }
+            FormatPlaceholder::Delta => clock::format_duration(record.clock.delta, output),
+            FormatPlaceholder::DeltaMs => {
+                output.push_str(&record.clock.delta.as_millis().to_string());
+            }
+            FormatPlaceholder::DeltaUs => {
+                output.push_str(&record.clock.delta.as_micros().to_string());
+            }
+            FormatPlaceholder::ThreadName => {
+                output.push_str(&record::thread_name());
+            }
+            FormatPlaceholder::ThreadId => {
+                output.push_str(&format!("{:?}", thread::current().id()));
+            }
+            FormatPlaceholder::ProcessId => {
+                output.push_str(&process::id().to_string());
+            }
+            FormatPlaceholder::File => {
+                output.push_str(record.file);
+            }
+            FormatPlaceholder::Line => {
+                output.push_str(&record.line.to_string());
+            }
+            FormatPlaceholder::Target => {
+                output.push_str(record.target);
+            }
+            FormatPlaceholder::Context => {
+                if !record.context.is_empty() {
+                    output.push('[');
+                    for (i, (key, value)) in record.context.iter().enumerate() {
+                        if i > 0 {
+                            output.push_str(", ");
+                        }
+                        output.push_str(&format!("{}={}", key, value));
+                    }
+                    output.push_str("] ");
+                }
+            }
+            FormatPlaceholder::Field { name, default } => {
+                if let Some(value) = field_value(record, name).or_else(|| default.clone()) {
+                    output.push_str(&value);
+                }
+            }
+            FormatPlaceholder::Fields => {
+                #[cfg(feature = "structured")]
+                self.push_remaining_fields(record, output);
+            }
+            FormatPlaceholder::Custom(name) => match registered_placeholder(name) {
+                Some(provider) => provider(record, output),
+                // Never registered, keep the placeholder visible in the output
+                None => {
+                    output.push('{');
+                    output.push_str(name);
+                    output.push('}');
+                }
+            },
+            FormatPlaceholder::Text(text) => {
+                output.push_str(text);
+            }
+        }
+    }
+
+    #[cfg(feature = "structured")]
+    fn places_field(&self, key: &str) -> bool {
+        self.parts.iter().any(|(placeholder, _)| {
+            matches!(placeholder, FormatPlaceholder::Field { name, .. } if name == key)
+        })
+    }
+
+    #[cfg(feature = "structured")]
+    fn has_fields_block(&self) -> bool {
+        self.parts
+            .iter()
+            .any(|(placeholder, _)| matches!(placeholder, FormatPlaceholder::Fields))
+    }
+
+    // `[k=v, ...]` with the fields the template hasn't placed on their own
+    #[cfg(feature = "structured")]
+    fn push_remaining_fields(&self, record: &Record, output: &mut String) {
+        let mut remaining = record
+            .fields
+            .into_iter()
+            .flatten()
+            .filter(|(key, _)| !self.places_field(key))
+            .peekable();
+        if remaining.peek().is_none() {
+            return;
+        }
+
+        output.push('[');
+        for (i, (key, value)) in remaining.enumerate() {
+            if i > 0 {
+                output.push_str(", ");
+            }
+            output.push_str(&format!("{}={}", key, value));
+        }
+        output.push(']');
+    }
+}
+
+fn parse_placeholder(
+    content: &str,
+    strict: bool,
+) -> Result<(FormatPlaceholder, Option<FormatSpec>), FormatErrorKind> {
+    let (name, mut spec) = match content.split_once(':') {
+        Some((name, spec)) => (name, Some(spec)),
+        None => (content, None),
+    };
+
+    // For the clock placeholders an argument with a `%` is a strftime pattern, anything
+    // else is the usual layout spec, e.g. `{time:>12}`
+    if let ("time" | "date" | "datetime", Some(argument)) = (name, spec) {
+        if argument.contains('%') {
+            if StrftimeItems::new(argument).any(|item| item == Item::Error) {
+                return Err(FormatErrorKind::InvalidTimeFormat(argument.to_string()));
+            }
+            return Ok((FormatPlaceholder::Timestamp(argument.to_string()), None));
+        }
+        if FormatSpec::parse(argument).is_none() {
+            return Err(FormatErrorKind::InvalidTimeFormat(argument.to_string()));
+        }
+    }
+
+    let placeholder = match name {
+        "level" => FormatPlaceholder::Level,
+        "symbol" => FormatPlaceholder::Symbol,
+        "message" => FormatPlaceholder::Message,
+        "time" => FormatPlaceholder::Time,
+        "date" => FormatPlaceholder::Date,
+        "datetime" => FormatPlaceholder::DateTime,
+        "utc" => FormatPlaceholder::Utc,
+        "epoch" => FormatPlaceholder::Epoch,
+        "epoch_ms" => FormatPlaceholder::EpochMs,
+        "elapsed" => FormatPlaceholder::Elapsed,
+        "elapsed_ms" => FormatPlaceholder::ElapsedMs,
+        "elapsed_us" => FormatPlaceholder::ElapsedUs,
+        "delta" => FormatPlaceholder::Delta,
+        "delta_ms" => FormatPlaceholder::DeltaMs,
+        "delta_us" => FormatPlaceholder::DeltaUs,
+        "thread" => FormatPlaceholder::ThreadName,
+        "thread_id" => FormatPlaceholder::ThreadId,
+        "pid" => FormatPlaceholder::ProcessId,
+        "file" => FormatPlaceholder::File,
+        "line" => FormatPlaceholder::Line,
+        "target" => FormatPlaceholder::Target,
+        "context" => FormatPlaceholder::Context,
+        "fields" => FormatPlaceholder::Fields,
+        // `{field:name}`, `{field:name|default}`, with an optional layout spec after the
+        // name, `{field:name:>8|default}`. The default is taken verbatim up to the closing
+        // brace, so it may contain colons, e.g. `{field:addr|0.0.0.0:80}`.
+        "field" => {
+            let argument = spec.unwrap_or_default();
+            let (argument, default) = match argument.split_once('|') {
+                Some((argument, default)) => (argument, Some(default)),
+                None => (argument, None),
+            };
+            let (name, layout) = match argument.split_once(':') {
+                Some((name, layout)) => (name, Some(layout)),
+                None => (argument, None),
+            };
+            if name.is_empty() {
+                return Err(FormatErrorKind::MissingFieldName);
+            }
+            spec = layout;
+            FormatPlaceholder::Field {
+                name: name.to_string(),
+                default: default.map(str::to_string),
+            }
+        }
+        // Lenient templates may name placeholders that get registered later on
+        _ if is_identifier(name) && (!strict || registered_placeholder(name).is_some()) => {
+            FormatPlaceholder::Custom(name.to_string())
+        }
+        _ => return Err(FormatErrorKind::UnknownPlaceholder(name.to_string())),
+    };
+
+    let spec = match spec {
+        Some(spec) => Some(
+            FormatSpec::parse(spec)
+                .ok_or_else(|| FormatErrorKind::InvalidSpec(spec.to_string()))?,
+        ),
+        None => None,
+    };
+
+    Ok((placeholder, spec))
+}
+
+// Field value as shown in templates, strings without their JSON quotes
+#[cfg(feature = "structured")]
+fn field_value(record: &Record, name: &str) -> Option<String> {
+    record.field(name).map(|value| match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    })
+}
+
+#[cfg(not(feature = "structured"))]
+fn field_value(_record: &Record, _name: &str) -> Option<String> {
+    None
+}
+
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{clock::ClockState, levels::LogLevel};
+
+    fn placeholder(content: &str) -> (FormatPlaceholder, Option<FormatSpec>) {
+        parse_placeholder(content, true).unwrap()
+    }
+
+    fn error(content: &str) -> FormatErrorKind {
+        parse_placeholder(content, true).unwrap_err()
+    }
+
+    fn render(template: &str) -> String {
+        let record = Record {
+            level: LogLevel::INFO,
+            message: "hello",
+            target: "test",
+            file: "test.rs",
+            line: 1,
+            clock: ClockState::default().sample(),
+            context: Vec::new(),
+            #[cfg(feature = "structured")]
+            fields: None,
+        };
+        FormatTemplate::try_parse(template).unwrap().render(&record)
+    }
+
+    fn spec(spec: &str) -> FormatSpec {
+        FormatSpec::parse(spec).unwrap()
+    }
+
+    #[test]
+    fn spec_syntax() {
+        assert_eq!(spec(""), FormatSpec::default());
+        assert_eq!(
+            spec("*^9.3"),
+            FormatSpec {
+                fill: '*',
+                align: Align::Center,
+                width: Some(9),
+                precision: Some(3),
+            }
+        );
+        assert_eq!(spec(">12").align, Align::Right);
+        assert!(FormatSpec::parse("x").is_none());
+        assert!(FormatSpec::parse("<7.x").is_none());
+    }
+
+    #[test]
+    fn field_name_and_default() {
+        let (field, spec) = placeholder("field:host");
+        assert!(
+            matches!(field, FormatPlaceholder::Field { name, default: None } if name == "host")
+        );
+        assert!(spec.is_none());
+
+        let (field, _) = placeholder("field:port|?");
+        assert!(
+            matches!(field, FormatPlaceholder::Field { default: Some(default), .. } if default == "?")
+        );
+    }
+
+    #[test]
+    fn field_default_keeps_colons() {
+        for default in ["0.0.0.0:80", "12:30", "http://x", "a:>5"] {
+            let (field, spec) = placeholder(&format!("field:addr|{}", default));
+            assert!(
+                matches!(&field, FormatPlaceholder::Field { name, default: Some(value) } if name == "addr" && value == default),
+                "{:?}",
+                field
+            );
+            assert!(spec.is_none());
+        }
+    }
+
+    #[test]
+    fn field_spec_before_default() {
+        let (field, spec) = placeholder("field:port:>5|?");
+        assert!(
+            matches!(field, FormatPlaceholder::Field { default: Some(default), .. } if default == "?")
+        );
+        assert_eq!(spec.unwrap().width, Some(5));
+
+        let (_, spec) = placeholder("field:port:>5");
+        assert_eq!(spec.unwrap().align, Align::Right);
+    }
+
+    #[test]
+    fn field_errors() {
+        assert_eq!(error("field"), FormatErrorKind::MissingFieldName);
+        assert_eq!(error("field:|x"), FormatErrorKind::MissingFieldName);
+        assert_eq!(
+            error("field:port:x|?"),
+            FormatErrorKind::InvalidSpec("x".to_string())
+        );
+    }
+
+    #[test]
+    fn rendered_field_defaults() {
+        assert_eq!(render("[{field:addr|0.0.0.0:80}]"), "[0.0.0.0:80]");
+        assert_eq!(render("[{field:port:>3|?}]"), "[  ?]");
+    }
+
+    #[test]
+    fn clock_pattern_or_spec() {
+        let (time, spec) = placeholder("time:%H:%M:%S");
+        assert!(matches!(time, FormatPlaceholder::Timestamp(pattern) if pattern == "%H:%M:%S"));
+        assert!(spec.is_none());
+
+        let (time, spec) = placeholder("time:>10");
+        assert!(matches!(time, FormatPlaceholder::Time));
+        assert_eq!(spec.unwrap().width, Some(10));
+
+        let (date, spec) = placeholder("date");
+        assert!(matches!(date, FormatPlaceholder::Date));
+        assert!(spec.is_none());
+    }
+
+    #[test]
+    fn clock_errors() {
+        assert_eq!(
+            error("time:%Q"),
+            FormatErrorKind::InvalidTimeFormat("%Q".to_string())
+        );
+        // Neither a pattern nor a spec
+        assert_eq!(
+            error("datetime:HH"),
+            FormatErrorKind::InvalidTimeFormat("HH".to_string())
+        );
+    }
+
+    #[test]
+    fn rendered_clock_layout() {
+        let rendered = render("[{time:>10}]");
+        assert_eq!(rendered.len(), 12);
+        assert!(rendered.starts_with("[  "));
+        assert_eq!(render("{date:%Y}").len(), 4);
+    }
+
+    #[test]
+    fn template_errors() {
+        let error = FormatTemplate::try_parse("a {nope}").unwrap_err();
+        assert_eq!(error.position, 2);
+        assert_eq!(
+            error.kind,
+            FormatErrorKind::UnknownPlaceholder("nope".to_string())
+        );
+        assert_eq!(
+            FormatTemplate::try_parse("{message").unwrap_err().kind,
+            FormatErrorKind::UnclosedBrace
+        );
+        assert_eq!(render("{{{message}}}"), "{hello}");
     }
 }
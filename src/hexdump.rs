@@ -0,0 +1,181 @@
+use std::{fmt, ops::Range};
+use termion::style;
+
+// Formats a byte buffer like pwntools' `hexdump`, offset, hex bytes and ASCII gutter:
+//
+// 00000000  7f 45 4c 46  02 01 01 00  00 00 00 00  00 00 00 00  │.ELF............│
+// *
+// 00000040
+//
+// Highlighted bytes are inverted. Sinks without color get the dump with the escapes removed.
+#[derive(Debug, Clone)]
+pub struct Hexdump<'a> {
+    data: &'a [u8],
+    width: usize,
+    group: usize,
+    offset: u64,
+    highlights: Vec<Range<usize>>,
+    collapse: bool,
+}
+
+impl<'a> Hexdump<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            width: 16,
+            group: 4,
+            offset: 0,
+            highlights: Vec::new(),
+            collapse: true,
+        }
+    }
+
+    // Bytes per line
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    // Bytes per group, groups are separated by an extra space
+    pub fn group(mut self, group: usize) -> Self {
+        self.group = group.max(1);
+        self
+    }
+
+    // Address of the first byte, e.g. where the buffer was leaked from
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    // Byte range relative to the start of the buffer, can be called repeatedly
+    pub fn highlight(mut self, range: Range<usize>) -> Self {
+        self.highlights.push(range);
+        self
+    }
+
+    // Repeated lines are replaced by a single `*`, on by default
+    pub fn collapse(mut self, collapse: bool) -> Self {
+        self.collapse = collapse;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn highlighted(&self, index: usize) -> bool {
+        self.highlights.iter().any(|range| range.contains(&index))
+    }
+
+    // Inverts whole runs of highlighted bytes, so the separators between them are inverted too
+    fn write_cell(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        start: usize,
+        i: usize,
+        len: usize,
+        cell: fmt::Arguments<'_>,
+    ) -> fmt::Result {
+        let highlighted = self.highlighted(start + i);
+        if highlighted && (i == 0 || !self.highlighted(start + i - 1)) {
+            write!(f, "{}", style::Invert)?;
+        }
+        f.write_fmt(cell)?;
+        if highlighted && (i + 1 == len || !self.highlighted(start + i + 1)) {
+            write!(f, "{}", style::NoInvert)?;
+        }
+        Ok(())
+    }
+
+    fn write_line(&self, f: &mut fmt::Formatter<'_>, start: usize, line: &[u8]) -> fmt::Result {
+        write!(f, "{:08x}  ", self.offset + start as u64)?;
+
+        for i in 0..self.width {
+            if i > 0 {
+                f.write_str(if i % self.group == 0 { "  " } else { " " })?;
+            }
+            let Some(byte) = line.get(i) else {
+                f.write_str("  ")?;
+                continue;
+            };
+
+            self.write_cell(f, start, i, line.len(), format_args!("{:02x}", byte))?;
+        }
+
+        f.write_str("  │")?;
+        for (i, byte) in line.iter().enumerate() {
+            let c = if byte.is_ascii_graphic() || *byte == b' ' {
+                *byte as char
+            } else {
+                '.'
+            };
+            self.write_cell(f, start, i, line.len(), format_args!("{}", c))?;
+        }
+        for _ in line.len()..self.width {
+            f.write_str(" ")?;
+        }
+        f.write_str("│\n")
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> From<&'a T> for Hexdump<'a> {
+    fn from(data: &'a T) -> Self {
+        Self::new(data.as_ref())
+    }
+}
+
+impl fmt::Display for Hexdump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut previous: Option<&[u8]> = None;
+        let mut collapsed = false;
+
+        for (index, line) in self.data.chunks(self.width).enumerate() {
+            let start = index * self.width;
+            // Highlighted lines are always shown, they're what the reader is looking for
+            let repeated = self.collapse
+                && previous == Some(line)
+                && line.len() == self.width
+                && !(start..start + self.width).any(|i| self.highlighted(i));
+            previous = Some(line);
+
+            if repeated {
+                if !collapsed {
+                    f.write_str("*\n")?;
+                    collapsed = true;
+                }
+                continue;
+            }
+            collapsed = false;
+            self.write_line(f, start, line)?;
+        }
+
+        // The end offset tells how much a trailing `*` swallowed
+        write!(f, "{:08x}", self.offset + self.data.len() as u64)
+    }
+}
+
+// Removes the escapes added for highlighting, for sinks that don't do color
+pub(crate) fn strip_ansi(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            output.push(c);
+            continue;
+        }
+        // CSI sequences end with a byte in the `@`..=`~` range
+        if chars.next() == Some('[') {
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) {
+                    break;
+                }
+            }
+        }
+    }
+    output
+}
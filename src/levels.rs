@@ -1,35 +1,184 @@
+use crate::LogError;
+use std::{cmp::Ordering, fmt, str::FromStr, sync::RwLock};
 use termion::color;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum LogLevel {
-    DEBUG = 0,
-    INFO = 1,
-    WARN = 2,
-    ERROR = 3,
-    SUCCESS = 4,
-    FAILURE = 5,
+    TRACE,
+    DEBUG,
+    INFO,
+    WARN,
+    ERROR,
+    SUCCESS,
+    FAILURE,
+    // Registered at runtime through `LogLevel::register`
+    Custom(CustomLevelId),
 }
 
+// Handle to a registered custom level, only obtainable through `LogLevel::register`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomLevelId(u8);
+
+struct CustomLevel {
+    name: &'static str,
+    symbol: &'static str,
+    color: color::Rgb,
+    severity: u8,
+}
+
+// Ids 0..BUILTIN are the built-in levels, custom levels follow
+const BUILTIN: u8 = 7;
+const MAX_CUSTOM: usize = (u8::MAX - BUILTIN) as usize + 1;
+
+static CUSTOM: RwLock<Vec<CustomLevel>> = RwLock::new(Vec::new());
+
 impl LogLevel {
-    pub const fn symbol(&self) -> &str {
+    // Adds a level with its own symbol and color, e.g. `[*] VULN`. The severity decides
+    // where it sits relative to the built-in levels (TRACE 10, DEBUG 20, INFO 30, WARN 40,
+    // ERROR 50, SUCCESS 60, FAILURE 70) for `min_level` and filter directives.
+    // Registering a name twice hands back the existing level.
+    pub fn register(name: &str, symbol: &str, color: color::Rgb, severity: u8) -> Self {
+        let mut custom = CUSTOM.write().unwrap();
+        if let Some(index) = custom
+            .iter()
+            .position(|level| level.name.eq_ignore_ascii_case(name))
+        {
+            return Self::Custom(CustomLevelId(index as u8));
+        }
+
+        assert!(custom.len() < MAX_CUSTOM, "too many custom log levels");
+        custom.push(CustomLevel {
+            // Levels live for the rest of the program, so leaking them keeps the accessors cheap
+            name: Box::leak(name.to_string().into_boxed_str()),
+            symbol: Box::leak(symbol.to_string().into_boxed_str()),
+            color,
+            severity,
+        });
+        Self::Custom(CustomLevelId((custom.len() - 1) as u8))
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::TRACE => "TRACE",
+            Self::DEBUG => "DEBUG",
+            Self::INFO => "INFO",
+            Self::WARN => "WARN",
+            Self::ERROR => "ERROR",
+            Self::SUCCESS => "SUCCESS",
+            Self::FAILURE => "FAILURE",
+            Self::Custom(id) => Self::custom(*id, |level| level.name),
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
         match self {
             Self::INFO => "[>]",
+            Self::TRACE => "[.]",
             Self::DEBUG => "[#]",
             Self::WARN => "[!]",
             Self::ERROR => "[x]",
             Self::SUCCESS => "[+]",
             Self::FAILURE => "[-]",
+            Self::Custom(id) => Self::custom(*id, |level| level.symbol),
         }
     }
 
-    pub fn color(&self) -> impl color::Color {
+    pub fn color(&self) -> color::Rgb {
         match *self {
             Self::INFO => color::Rgb(255, 255, 255),
+            Self::TRACE => color::Rgb(128, 128, 128),
             Self::DEBUG => color::Rgb(100, 100, 255),
             Self::WARN => color::Rgb(255, 165, 0),
             Self::ERROR => color::Rgb(255, 0, 0),
             Self::SUCCESS => color::Rgb(0, 255, 0),
             Self::FAILURE => color::Rgb(139, 0, 0),
+            Self::Custom(id) => Self::custom(id, |level| level.color),
+        }
+    }
+
+    pub fn severity(&self) -> u8 {
+        match self {
+            Self::TRACE => 10,
+            Self::DEBUG => 20,
+            Self::INFO => 30,
+            Self::WARN => 40,
+            Self::ERROR => 50,
+            Self::SUCCESS => 60,
+            Self::FAILURE => 70,
+            Self::Custom(id) => Self::custom(*id, |level| level.severity),
+        }
+    }
+
+    fn custom<T>(id: CustomLevelId, get: impl FnOnce(&CustomLevel) -> T) -> T {
+        get(&CUSTOM.read().unwrap()[id.0 as usize])
+    }
+
+    // Compact encoding so a level fits into an atomic
+    pub(crate) const fn id(&self) -> u8 {
+        match self {
+            Self::TRACE => 0,
+            Self::DEBUG => 1,
+            Self::INFO => 2,
+            Self::WARN => 3,
+            Self::ERROR => 4,
+            Self::SUCCESS => 5,
+            Self::FAILURE => 6,
+            Self::Custom(id) => BUILTIN + id.0,
+        }
+    }
+
+    pub(crate) const fn from_id(id: u8) -> Self {
+        match id {
+            0 => Self::TRACE,
+            1 => Self::DEBUG,
+            2 => Self::INFO,
+            3 => Self::WARN,
+            4 => Self::ERROR,
+            5 => Self::SUCCESS,
+            6 => Self::FAILURE,
+            _ => Self::Custom(CustomLevelId(id - BUILTIN)),
+        }
+    }
+}
+
+// Ordered by severity, levels sharing a severity fall back to registration order
+impl Ord for LogLevel {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.severity(), self.id()).cmp(&(other.severity(), other.id()))
+    }
+}
+
+impl PartialOrd for LogLevel {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for LogLevel {
+    type Err = LogError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "trace" => Ok(Self::TRACE),
+            "debug" => Ok(Self::DEBUG),
+            "info" => Ok(Self::INFO),
+            "warn" | "warning" => Ok(Self::WARN),
+            "error" => Ok(Self::ERROR),
+            "success" => Ok(Self::SUCCESS),
+            "failure" => Ok(Self::FAILURE),
+            other => CUSTOM
+                .read()
+                .unwrap()
+                .iter()
+                .position(|level| level.name.eq_ignore_ascii_case(other))
+                .map(|index| Self::Custom(CustomLevelId(index as u8)))
+                .ok_or_else(|| LogError::new(format!("unknown log level '{}'", other))),
         }
     }
 }
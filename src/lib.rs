@@ -1,48 +1,105 @@
+mod background;
+mod clock;
+mod color;
+mod context;
+mod filter;
+mod flush;
 mod format;
+mod hexdump;
 mod levels;
+mod live;
+mod logfmt;
 mod macros;
+mod progress;
+mod record;
+mod rotation;
+mod sink;
 mod timing;
 
-use chrono::Local;
 use std::{
-    fmt,
+    env, fmt,
     io::{self, Write},
+    panic,
+    path::Path,
     sync::{
         atomic::{AtomicBool, AtomicU8, Ordering},
-        Mutex, OnceLock,
+        Arc, Once, OnceLock, RwLock,
     },
 };
-use std::{process, thread};
-use termion::color;
 
 #[cfg(feature = "structured")]
 mod structured;
 
-pub use format::{FormatPlaceholder, FormatTemplate};
-pub use levels::LogLevel;
+#[cfg(feature = "log")]
+mod log_bridge;
+
+#[cfg(feature = "tracing")]
+mod tracing_layer;
+
+pub use background::OverflowPolicy;
+pub use clock::{DeltaScope, Timezone};
+pub use color::ColorMode;
+pub use context::{spawn_with_context, ContextGuard, ContextSnapshot, SnapshotGuard};
+pub use filter::FILTER_ENV;
+pub use flush::FlushPolicy;
+pub use format::{
+    Align, FormatError, FormatErrorKind, FormatPlaceholder, FormatSpec, FormatTemplate,
+};
+pub use hexdump::Hexdump;
+pub use levels::{CustomLevelId, LogLevel};
+pub use progress::{MultiProgress, Progress, ProgressReader, ProgressWriter};
+pub use record::Record;
+pub use rotation::{RotatingFileWriter, Rotation, RotationSuffix};
+pub use sink::Sink;
 pub use timing::TimedOperation;
 
+use background::{Background, Job, QueuedRecord};
+use clock::ClockState;
+use filter::Filter;
+use flush::FlushState;
+use sink::{shared_writer, OutputFormat, DEFAULT_FORMAT, DEFAULT_SINK, VERBOSE_FORMAT};
+
 #[cfg(feature = "structured")]
-pub use structured::structured::LogEvent;
+pub use structured::LogEvent;
+
+#[cfg(feature = "log")]
+pub use log_bridge::init_log_bridge;
+
+#[cfg(feature = "tracing")]
+pub use tracing_layer::HackerlogLayer;
 
 // Global logger configuration
 pub struct Logger {
     verbose: AtomicBool,
+    verbose_format: RwLock<FormatTemplate>,
     min_level: AtomicU8,
-    writer: Mutex<Box<dyn Write + Send>>,
-    context: Mutex<Vec<(String, String)>>,
-    format: Mutex<FormatTemplate>,
+    filter: RwLock<Filter>,
+    color_mode: AtomicU8,
+    clock: ClockState,
+    // Shared with the timer thread of `FlushPolicy::Interval`
+    sinks: Arc<RwLock<Vec<Sink>>>,
+    background: RwLock<Option<Arc<Background>>>,
+    flush: FlushState,
 }
 
 impl Default for Logger {
+    // Picks up filter directives from the HACKERLOG environment variable, invalid ones are ignored
     fn default() -> Self {
-        Self {
+        let logger = Self {
             verbose: AtomicBool::new(false),
-            min_level: AtomicU8::new(LogLevel::INFO as u8),
-            writer: Mutex::new(Box::new(io::stdout())),
-            context: Mutex::new(Vec::new()),
-            format: Mutex::new(FormatTemplate::parse("{symbol} {context}{message}")),
-        }
+            verbose_format: RwLock::new(FormatTemplate::parse(VERBOSE_FORMAT)),
+            min_level: AtomicU8::new(LogLevel::INFO.id()),
+            filter: RwLock::new(Filter::default()),
+            color_mode: AtomicU8::new(ColorMode::Auto.as_u8()),
+            clock: ClockState::default(),
+            sinks: Arc::new(RwLock::new(vec![
+                Sink::stdout(DEFAULT_SINK).format(DEFAULT_FORMAT)
+            ])),
+            background: RwLock::new(None),
+            flush: FlushState::default(),
+        };
+        logger.filter_from_env(FILTER_ENV).ok();
+        logger
     }
 }
 
@@ -54,8 +111,22 @@ pub fn logger() -> &'static Logger {
 }
 
 impl Logger {
+    // Sets the layout of the default sink
     pub fn set_format(&self, template: &str) -> &Self {
-        *self.format.lock().unwrap() = FormatTemplate::parse(template);
+        self.set_default_output(OutputFormat::Template(FormatTemplate::parse(template)))
+    }
+
+    // Like `set_format`, but rejects templates with unknown placeholders or stray braces
+    pub fn try_set_format(&self, template: &str) -> Result<&Self, FormatError> {
+        let template = FormatTemplate::try_parse(template)?;
+        Ok(self.set_default_output(OutputFormat::Template(template)))
+    }
+
+    fn set_default_output(&self, format: OutputFormat) -> &Self {
+        let mut sinks = self.sinks.write().unwrap();
+        if let Some(sink) = sinks.iter_mut().find(|sink| sink.name == DEFAULT_SINK) {
+            sink.format = format;
+        }
         self
     }
 
@@ -72,34 +143,177 @@ impl Logger {
         self.set_format("{datetime} [{level}] <{file}:{line}> {message}")
     }
 
+    // While enabled, every template based sink renders the verbose layout instead of its own
     pub fn verbose(&self, enabled: bool) -> &Self {
         self.verbose.store(enabled, Ordering::Relaxed);
         self
     }
 
+    // Layout used while verbose mode is on, regular formats stay untouched
+    pub fn set_verbose_format(&self, template: &str) -> &Self {
+        *self.verbose_format.write().unwrap() = FormatTemplate::parse(template);
+        self
+    }
+
+    // Makes `{name}` available in templates, the provider writes its value for each record
+    pub fn register_placeholder<F>(&self, name: &str, provider: F) -> &Self
+    where
+        F: Fn(&Record, &mut String) + Send + Sync + 'static,
+    {
+        format::register_placeholder(name, Arc::new(provider));
+        self
+    }
+
     pub fn min_level(&self, level: LogLevel) -> &Self {
-        self.min_level.store(level as u8, Ordering::Relaxed);
+        self.min_level.store(level.id(), Ordering::Relaxed);
+        self.sync_log_bridge()
+    }
+
+    // Directives like `info,myapp::net=debug,noisy_dep=warn`, matched against the
+    // caller's module path. A bare level replaces `min_level`, which still applies
+    // to every module without a directive of its own.
+    pub fn set_filter(&self, directives: &str) -> Result<&Self, LogError> {
+        let (default, filter) = Filter::parse(directives)?;
+        *self.filter.write().unwrap() = filter;
+        match default {
+            Some(level) => self.min_level(level),
+            None => self.sync_log_bridge(),
+        };
+        Ok(self)
+    }
+
+    // Applies the directives found in `var`, leaving the filter alone if it's unset
+    pub fn filter_from_env(&self, var: &str) -> Result<&Self, LogError> {
+        match env::var(var) {
+            Ok(directives) => self.set_filter(&directives),
+            Err(_) => Ok(self),
+        }
+    }
+
+    fn sync_log_bridge(&self) -> &Self {
+        #[cfg(feature = "log")]
+        log_bridge::sync_max_level(self.lowest_enabled_level());
+        self
+    }
+
+    // Most verbose level that can make it through for some module
+    #[cfg(feature = "log")]
+    pub(crate) fn lowest_enabled_level(&self) -> LogLevel {
+        let min_level = self.current_min_level();
+        match self.filter.read().unwrap().lowest_level() {
+            Some(level) => level.min(min_level),
+            None => min_level,
+        }
+    }
+
+    // Default color mode for sinks that don't set their own
+    pub fn color_mode(&self, mode: ColorMode) -> &Self {
+        self.color_mode.store(mode.as_u8(), Ordering::Relaxed);
+        self
+    }
+
+    // Timezone for `{time}`, `{date}`, `{datetime}` and the JSON timestamp
+    pub fn timezone(&self, timezone: Timezone) -> &Self {
+        self.clock.set_timezone(timezone);
         self
     }
 
+    // Starts `{elapsed}` over, e.g. right before firing an exploit
+    pub fn reset_elapsed(&self) -> &Self {
+        self.clock.reset();
+        self
+    }
+
+    pub fn delta_scope(&self, scope: DeltaScope) -> &Self {
+        self.clock.set_delta_scope(scope);
+        self
+    }
+
+    pub fn current_min_level(&self) -> LogLevel {
+        LogLevel::from_id(self.min_level.load(Ordering::Relaxed))
+    }
+
+    // Replaces the writer of the default sink, re-creating the sink if it was removed.
+    // The new writer is not considered a terminal, so it stays plain text under `ColorMode::Auto`.
     pub fn set_writer(&self, writer: Box<dyn Write + Send>) -> io::Result<()> {
-        *self.writer.lock().unwrap() = writer;
+        let mut sinks = self.sinks.write().unwrap();
+        match sinks.iter_mut().find(|sink| sink.name == DEFAULT_SINK) {
+            Some(sink) => {
+                sink.writer = shared_writer(writer);
+                sink.is_terminal = false;
+            }
+            None => sinks.push(Sink::from_boxed(DEFAULT_SINK, writer)),
+        }
         Ok(())
     }
 
+    // Log to `path`, rolling it over according to `rotation` and keeping `max_files` old logs
+    pub fn set_rotating_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+        rotation: Rotation,
+        max_files: usize,
+    ) -> io::Result<()> {
+        let writer = RotatingFileWriter::new(path, rotation)?.max_files(max_files);
+        self.set_writer(Box::new(writer))
+    }
+
+    // Adds a sink, replacing any existing sink with the same name
+    pub fn add_sink(&self, sink: Sink) -> &Self {
+        let mut sinks = self.sinks.write().unwrap();
+        match sinks.iter_mut().find(|existing| existing.name == sink.name) {
+            Some(existing) => *existing = sink,
+            None => sinks.push(sink),
+        }
+        self
+    }
+
+    // Returns whether a sink with that name existed
+    pub fn remove_sink(&self, name: &str) -> bool {
+        let mut sinks = self.sinks.write().unwrap();
+        let before = sinks.len();
+        sinks.retain(|sink| sink.name != name);
+        sinks.len() != before
+    }
+
+    pub fn sink_names(&self) -> Vec<String> {
+        let sinks = self.sinks.read().unwrap();
+        sinks.iter().map(|sink| sink.name.clone()).collect()
+    }
+
+    // Context for the lines of the calling thread
     pub fn add_context<K, V>(&self, key: K, value: V) -> ContextGuard
     where
         K: Into<String>,
         V: Into<String>,
     {
-        let mut context = self.context.lock().unwrap();
-        context.push((key.into(), value.into()));
-        ContextGuard(context.len() - 1)
+        context::push_thread(key.into(), value.into())
+    }
+
+    // Context shown on the lines of every thread, not just the calling one
+    pub fn add_global_context<K, V>(&self, key: K, value: V) -> ContextGuard
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        context::push_global(key.into(), value.into())
     }
 
     pub fn should_log(&self, level: LogLevel) -> bool {
-        let min_level = self.min_level.load(Ordering::Relaxed);
-        level as u8 >= min_level
+        level >= self.current_min_level()
+    }
+
+    // Like `should_log`, but honors the filter directive matching `target`
+    pub fn should_log_target(&self, level: LogLevel, target: &str) -> bool {
+        let filter = self.filter.read().unwrap();
+        if filter.is_empty() {
+            return self.should_log(level);
+        }
+
+        match filter.level_for(target) {
+            Some(min_level) => level >= min_level,
+            None => self.should_log(level),
+        }
     }
 
     pub fn write_log(
@@ -109,83 +323,241 @@ impl Logger {
         file: &str,
         line: u32,
     ) -> io::Result<()> {
-        let format = self.format.lock().unwrap();
-        let mut output = String::new();
+        self.write_log_with_target(level, message, "", file, line)
+    }
 
-        for part in &format.parts {
-            match part {
-                FormatPlaceholder::Level => {
-                    output.push_str(&format!("{:?}", level));
-                }
-                FormatPlaceholder::Symbol => {
-                    output.push_str(level.symbol());
-                }
-                FormatPlaceholder::Message => {
-                    output.push_str(message);
-                }
-                FormatPlaceholder::Time => {
-                    output.push_str(&Local::now().format("%H:%M:%S").to_string());
-                }
-                FormatPlaceholder::Date => {
-                    output.push_str(&Local::now().format("%Y-%m-%d").to_string());
-                }
-                FormatPlaceholder::DateTime => {
-                    output.push_str(&Local::now().format("%Y-%m-%d %H:%M:%S").to_string());
-                }
-                FormatPlaceholder::ThreadName => {
-                    let name = thread::current().name().map_or_else(
-                        || format!("Thread-{:?}", thread::current().id()),
-                        ToString::to_string,
-                    );
-                    output.push_str(&name);
-                }
-                FormatPlaceholder::ThreadId => {
-                    output.push_str(&format!("{:?}", thread::current().id()));
-                }
-                FormatPlaceholder::ProcessId => {
-                    output.push_str(&process::id().to_string());
-                }
-                FormatPlaceholder::File => {
-                    output.push_str(file);
-                }
-                FormatPlaceholder::Line => {
-                    output.push_str(&line.to_string());
+    // `target` is the module path of the call site, as passed by the logging macros
+    pub fn write_log_with_target(
+        &self,
+        level: LogLevel,
+        message: &str,
+        target: &str,
+        file: &str,
+        line: u32,
+    ) -> io::Result<()> {
+        self.dispatch(Record {
+            level,
+            message,
+            target,
+            file,
+            line,
+            clock: self.clock.sample(),
+            context: context::capture(),
+            #[cfg(feature = "structured")]
+            fields: None,
+        })
+    }
+
+    // Shows, updates or (with `None`) removes a status line. Lines are drawn on the first
+    // terminal sink only, two regions on the same TTY would move the cursor over each other.
+    // Returns false when there is no terminal to draw on, callers fall back to regular
+    // records then.
+    pub(crate) fn set_live_line(&self, id: u64, line: Option<String>) -> bool {
+        let sinks = self.sinks.read().unwrap();
+        let mut live = sinks.iter().filter(|sink| sink.shows_live());
+        let Some(first) = live.next() else {
+            return false;
+        };
+
+        first.writer.lock().unwrap().set_live(id, line.clone()).ok();
+        // The terminal sink drawing the line may have changed since it was shown
+        if line.is_none() {
+            for sink in live {
+                sink.writer.lock().unwrap().set_live(id, None).ok();
+            }
+        }
+        true
+    }
+
+    fn live_sink(&self) -> Option<String> {
+        let sinks = self.sinks.read().unwrap();
+        let sink = sinks.iter().find(|sink| sink.shows_live())?;
+        Some(sink.name.clone())
+    }
+
+    // Status updates for the sinks that don't show them in place, i.e. files, pipes and
+    // any terminal besides the one drawing the status lines
+    pub(crate) fn write_status(
+        &self,
+        level: LogLevel,
+        message: &str,
+        file: &str,
+        line: u32,
+    ) -> io::Result<()> {
+        let record = Record {
+            level,
+            message,
+            target: "",
+            file,
+            line,
+            clock: self.clock.sample(),
+            context: context::capture(),
+            #[cfg(feature = "structured")]
+            fields: None,
+        };
+        let live = self.live_sink();
+        self.dispatch_to(record, |sink| live.as_deref() != Some(sink.name.as_str()))
+    }
+
+    fn dispatch(&self, record: Record) -> io::Result<()> {
+        self.dispatch_to(record, |_| true)
+    }
+
+    fn dispatch_to(&self, record: Record, include: impl Fn(&Sink) -> bool) -> io::Result<()> {
+        let color_mode = ColorMode::from_u8(self.color_mode.load(Ordering::Relaxed));
+        let verbose = || {
+            self.verbose
+                .load(Ordering::Relaxed)
+                .then(|| self.verbose_format.read().unwrap())
+        };
+
+        let background = self.background.read().unwrap().clone();
+        if let Some(background) = background {
+            // Reported before any lock is taken, the report goes through here as well
+            let unreported = background.take_unreported(false);
+            if unreported > 0 {
+                self.report_drops(unreported);
+            }
+
+            // Rendering stays on the calling thread, only the I/O is handed off.
+            // No locks are held while `push` blocks on a full queue.
+            let jobs: Vec<Job> = {
+                let verbose = verbose();
+                self.sinks
+                    .read()
+                    .unwrap()
+                    .iter()
+                    .filter(|sink| include(sink) && sink.accepts(record.level))
+                    .map(|sink| Job {
+                        writer: Arc::clone(&sink.writer),
+                        bytes: sink
+                            .render_line(&record, color_mode, verbose.as_deref())
+                            .into_bytes(),
+                    })
+                    .collect()
+            };
+            if !jobs.is_empty() {
+                background.push(QueuedRecord {
+                    jobs,
+                    flush: self.flush.should_flush(record.level),
+                });
+            }
+            return Ok(());
+        }
+
+        // Every sink gets its copy even if an earlier one failed, the first error is reported
+        let verbose = verbose();
+        let mut result = Ok(());
+        let sinks = self.sinks.read().unwrap();
+        let accepting: Vec<&Sink> = sinks
+            .iter()
+            .filter(|sink| include(sink) && sink.accepts(record.level))
+            .collect();
+        if accepting.is_empty() {
+            return result;
+        }
+
+        for sink in &accepting {
+            if let Err(err) = sink.write_record(&record, color_mode, verbose.as_deref()) {
+                if result.is_ok() {
+                    result = Err(err);
                 }
-                FormatPlaceholder::Context => {
-                    let context = self.context.lock().unwrap();
-                    if !context.is_empty() {
-                        output.push('[');
-                        for (i, (key, value)) in context.iter().enumerate() {
-                            if i > 0 {
-                                output.push_str(", ");
-                            }
-                            output.push_str(&format!("{}={}", key, value));
-                        }
-                        output.push_str("] ");
+            }
+        }
+
+        if self.flush.should_flush(record.level) {
+            for sink in &accepting {
+                if let Err(err) = sink.flush() {
+                    if result.is_ok() {
+                        result = Err(err);
                     }
                 }
-                FormatPlaceholder::Text(text) => {
-                    output.push_str(text);
-                }
             }
         }
 
-        output.push('\n');
+        result
+    }
 
-        // Color the output
-        let colored_output = format!(
-            "{}{}{}",
-            color::Fg(level.color()),
-            output,
-            color::Fg(color::Reset)
-        );
+    fn report_drops(&self, dropped: u64) {
+        let message = format!("Dropped {} log records, the async queue was full", dropped);
+        self.write_log_with_target(LogLevel::WARN, &message, module_path!(), file!(), line!())
+            .ok();
+    }
 
-        // Write to configured output
-        let mut writer = self.writer.lock().unwrap();
-        writer.write_all(colored_output.as_bytes())?;
-        writer.flush()?;
+    // Hands writing off to a background thread fed through a queue of `capacity` records.
+    // Dropping the guard writes out everything still queued and returns to synchronous
+    // writes, so keep it alive until the end of `main`.
+    pub fn enable_async(
+        &self,
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> io::Result<AsyncGuard<'_>> {
+        self.disable_async();
+        let background = Background::start(capacity, policy)?;
+        *self.background.write().unwrap() = Some(Arc::new(background));
+        Ok(AsyncGuard { logger: self })
+    }
 
-        Ok(())
+    fn disable_async(&self) {
+        let background = self.background.write().unwrap().take();
+        if let Some(background) = background {
+            background.shutdown();
+            let unreported = background.take_unreported(true);
+            if unreported > 0 {
+                self.report_drops(unreported);
+            }
+            self.flush().ok();
+        }
+    }
+
+    // Records lost to `OverflowPolicy::DropNewest`/`DropOldest` since async mode was enabled
+    pub fn dropped_records(&self) -> u64 {
+        let background = self.background.read().unwrap();
+        background.as_ref().map_or(0, |background| background.dropped())
+    }
+
+    // Waits for queued records to be written and flushes every sink
+    pub fn flush(&self) -> io::Result<()> {
+        let background = self.background.read().unwrap().clone();
+        if let Some(background) = background {
+            background.wait_idle();
+        }
+
+        let mut result = Ok(());
+        for sink in self.sinks.read().unwrap().iter() {
+            if let Err(err) = sink.flush() {
+                if result.is_ok() {
+                    result = Err(err);
+                }
+            }
+        }
+        result
+    }
+
+    // Sink output is buffered and pushed out according to `policy`. Anything still
+    // buffered is flushed by `flush`, a `FlushGuard`, or when a thread panics.
+    pub fn set_flush_policy(&self, policy: FlushPolicy) -> &Self {
+        self.flush.set_policy(policy, Arc::downgrade(&self.sinks));
+        if policy != FlushPolicy::EveryRecord {
+            install_panic_flush();
+        }
+        self
+    }
+
+    // Flushes every sink when dropped, keep it alive until the end of `main`
+    pub fn flush_guard(&self) -> FlushGuard<'_> {
+        FlushGuard { logger: self }
+    }
+
+    // Best effort flush from the panic hook, skipping anything the panicking thread may hold
+    fn flush_after_panic(&self) {
+        if let Ok(sinks) = self.sinks.try_read() {
+            for sink in sinks.iter() {
+                if let Ok(mut writer) = sink.writer.try_lock() {
+                    writer.flush().ok();
+                }
+            }
+        }
     }
 
     #[cfg(feature = "structured")]
@@ -193,47 +565,76 @@ impl Logger {
         self.set_format("{datetime} {level} {message}")
     }
 
+    // Switches the default sink to logfmt
+    pub fn use_logfmt_format(&self) -> &Self {
+        self.set_default_output(OutputFormat::Logfmt)
+    }
+
+    // Switches the default sink to JSON Lines
+    #[cfg(feature = "structured")]
+    pub fn use_json_format(&self) -> &Self {
+        self.set_default_output(OutputFormat::Json)
+    }
+
     #[cfg(feature = "structured")]
     pub fn write_structured_event(&self, event: &LogEvent) -> io::Result<()> {
-        let mut fields_str = String::new();
-        if !event.fields.is_empty() {
-            fields_str.push('[');
-            for (i, (key, value)) in event.fields.iter().enumerate() {
-                if i > 0 {
-                    fields_str.push_str(", ");
-                }
-                fields_str.push_str(&format!("{}={}", key, value));
-            }
-            fields_str.push(']');
-        }
+        self.dispatch(Record {
+            level: event.level,
+            message: &event.message,
+            target: &event.target,
+            file: &event.file,
+            line: event.line,
+            clock: self.clock.sample(),
+            context: context::capture(),
+            fields: Some(&event.fields),
+        })
+    }
+}
+
+// Keeps async mode alive, shuts the writer thread down after draining the queue on drop
+#[must_use = "async mode ends when the guard is dropped"]
+pub struct AsyncGuard<'a> {
+    logger: &'a Logger,
+}
+
+impl AsyncGuard<'_> {
+    pub fn flush(&self) -> io::Result<()> {
+        self.logger.flush()
+    }
+}
 
-        self.write_log(
-            event.level,
-            &if fields_str.is_empty() {
-                event.message.clone()
-            } else {
-                format!("{} {}", event.message, fields_str)
-            },
-            &event.file,
-            event.line,
-        )
+impl Drop for AsyncGuard<'_> {
+    fn drop(&mut self) {
+        self.logger.disable_async();
     }
 }
 
-// Context guard for automatic cleanup
-pub struct ContextGuard(usize);
+#[must_use = "the logger is flushed when the guard is dropped"]
+pub struct FlushGuard<'a> {
+    logger: &'a Logger,
+}
 
-impl Drop for ContextGuard {
+impl Drop for FlushGuard<'_> {
     fn drop(&mut self) {
-        if let Some(logger) = LOGGER.get() {
-            let mut context = logger.context.lock().unwrap();
-            if self.0 < context.len() {
-                context.remove(self.0);
-            }
-        }
+        self.logger.flush().ok();
     }
 }
 
+// Statics are never dropped, so a panic would otherwise lose whatever is still buffered
+fn install_panic_flush() {
+    static INSTALLED: Once = Once::new();
+
+    INSTALLED.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if let Some(logger) = LOGGER.get() {
+                logger.flush_after_panic();
+            }
+            previous(info);
+        }));
+    });
+}
+
 // Error handling
 #[derive(Debug)]
 pub struct LogError {
@@ -256,79 +657,14 @@ impl fmt::Display for LogError {
 
 impl std::error::Error for LogError {}
 
-impl From<io::Error> for LogError {
-    fn from(err: io::Error) -> Self {
+impl From<FormatError> for LogError {
+    fn from(err: FormatError) -> Self {
         LogError::new(err.to_string())
     }
 }
 
-// Progress indicator
-pub struct Progress {
-    message: String,
-    total: Option<u64>,
-    current: u64,
-}
-
-impl Progress {
-    pub fn new<S: Into<String>>(message: S) -> Self {
-        Self {
-            message: message.into(),
-            total: None,
-            current: 0,
-        }
-    }
-
-    pub fn with_total<S: Into<String>>(message: S, total: u64) -> Self {
-        Self {
-            message: message.into(),
-            total: Some(total),
-            current: 0,
-        }
-    }
-
-    pub fn inc(&mut self, amount: u64) {
-        self.current += amount;
-        let message = self.message.clone();
-        self.update(&message);
-    }
-
-    pub fn update<S: Into<String>>(&mut self, message: S) {
-        self.message = message.into();
-        if let Some(total) = self.total {
-            logger()
-                .write_log(
-                    LogLevel::INFO,
-                    &format!("{} [{}/{}]", self.message, self.current, total),
-                    file!(),
-                    line!(),
-                )
-                .ok();
-        } else {
-            logger()
-                .write_log(
-                    LogLevel::INFO,
-                    &format!("{} [{}]", self.message, self.current),
-                    file!(),
-                    line!(),
-                )
-                .ok();
-        }
-    }
-
-    pub fn finish(self) {
-        logger()
-            .write_log(
-                LogLevel::SUCCESS,
-                &format!("{} [Complete]", self.message),
-                file!(),
-                line!(),
-            )
-            .ok();
-    }
-
-    pub fn finish_with_message<S: Into<String>>(self, message: S) {
-        logger()
-            .write_log(LogLevel::SUCCESS, &message.into(), file!(), line!())
-            .ok();
+impl From<io::Error> for LogError {
+    fn from(err: io::Error) -> Self {
+        LogError::new(err.to_string())
     }
-}
\ No newline at end of file
+}
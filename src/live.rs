@@ -0,0 +1,65 @@
+use std::io::{self, Write};
+use termion::{clear, cursor};
+
+// Status lines pinned below the log output of a terminal sink, e.g. progress bars.
+// Log lines are written above them: the region is cleared, the line written, and the
+// region drawn again, so the cursor always sits at the end of the last status line.
+#[derive(Default)]
+pub(crate) struct LiveRegion {
+    lines: Vec<(u64, String)>,
+    // Lines currently on screen
+    drawn: usize,
+}
+
+impl LiveRegion {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.lines.is_empty() && self.drawn == 0
+    }
+
+    // Updates the line owned by `id` in place, new lines go to the bottom
+    pub(crate) fn set(&mut self, id: u64, line: Option<String>) {
+        let index = self.lines.iter().position(|(owner, _)| *owner == id);
+        match (index, line) {
+            (Some(index), Some(line)) => self.lines[index].1 = line,
+            (Some(index), None) => {
+                self.lines.remove(index);
+            }
+            (None, Some(line)) => self.lines.push((id, line)),
+            (None, None) => {}
+        }
+    }
+
+    pub(crate) fn clear<W: Write>(&mut self, out: &mut W) -> io::Result<()> {
+        if self.drawn == 0 {
+            return Ok(());
+        }
+
+        write!(out, "\r{}", clear::CurrentLine)?;
+        for _ in 1..self.drawn {
+            write!(out, "{}{}", cursor::Up(1), clear::CurrentLine)?;
+        }
+        self.drawn = 0;
+        Ok(())
+    }
+
+    pub(crate) fn draw<W: Write>(&mut self, out: &mut W) -> io::Result<()> {
+        // Wrapped lines would throw off the cursor movement in `clear`
+        let width = termion::terminal_size()
+            .map(|(width, _)| width as usize)
+            .ok()
+            .filter(|width| *width > 0)
+            .unwrap_or(80);
+        for (i, (_, line)) in self.lines.iter().enumerate() {
+            if i > 0 {
+                out.write_all(b"\n")?;
+            }
+            let end = line
+                .char_indices()
+                .nth(width - 1)
+                .map_or(line.len(), |(end, _)| end);
+            out.write_all(&line.as_bytes()[..end])?;
+        }
+        self.drawn = self.lines.len();
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{context, levels::LogLevel, logger, record::Record, Logger};
+use log::{LevelFilter, Metadata, SetLoggerError};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+// Routes `log::info!` and friends from any crate through hackerlog
+pub fn init_log_bridge() -> Result<(), SetLoggerError> {
+    log::set_logger(logger())?;
+    INSTALLED.store(true, Ordering::Relaxed);
+    log::set_max_level(level_filter(logger().lowest_enabled_level()));
+    Ok(())
+}
+
+// Keeps the facade's cheap level check in line with `Logger::min_level`
+pub(crate) fn sync_max_level(level: LogLevel) {
+    if INSTALLED.load(Ordering::Relaxed) {
+        log::set_max_level(level_filter(level));
+    }
+}
+
+const fn to_level(level: log::Level) -> LogLevel {
+    match level {
+        log::Level::Error => LogLevel::ERROR,
+        log::Level::Warn => LogLevel::WARN,
+        log::Level::Info => LogLevel::INFO,
+        log::Level::Debug => LogLevel::DEBUG,
+        log::Level::Trace => LogLevel::TRACE,
+    }
+}
+
+// Custom levels map onto the closest built-in level at or above them
+fn level_filter(level: LogLevel) -> LevelFilter {
+    if level <= LogLevel::TRACE {
+        LevelFilter::Trace
+    } else if level <= LogLevel::DEBUG {
+        LevelFilter::Debug
+    } else if level <= LogLevel::INFO {
+        LevelFilter::Info
+    } else if level <= LogLevel::WARN {
+        LevelFilter::Warn
+    } else {
+        LevelFilter::Error
+    }
+}
+
+impl log::Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.should_log_target(to_level(metadata.level()), metadata.target())
+    }
+
+    fn log(&self, record: &log::Record) {
+        let level = to_level(record.level());
+        if !self.should_log_target(level, record.target()) {
+            return;
+        }
+
+        let message = record.args().to_string();
+        self.dispatch(Record {
+            level,
+            message: &message,
+            target: record.target(),
+            file: record.file().unwrap_or("<unknown>"),
+            line: record.line().unwrap_or(0),
+            clock: self.clock.sample(),
+            context: context::capture(),
+            #[cfg(feature = "structured")]
+            fields: None,
+        })
+        .ok();
+    }
+
+    fn flush(&self) {
+        Logger::flush(self).ok();
+    }
+}
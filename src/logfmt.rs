@@ -0,0 +1,88 @@
+use crate::record::{self, Record};
+use chrono::SecondsFormat;
+use std::{fmt::Write, process};
+
+// One line of `key=value` pairs, see https://brandur.org/logfmt
+pub(crate) fn render_logfmt(record: &Record) -> String {
+    let mut output = String::new();
+    let time = record
+        .clock
+        .time
+        .to_rfc3339_opts(SecondsFormat::Millis, false);
+
+    push_pair(&mut output, "ts", &time);
+    push_pair(&mut output, "level", &record.level.name().to_lowercase());
+    push_pair(&mut output, "msg", record.message);
+    if !record.target.is_empty() {
+        push_pair(&mut output, "target", record.target);
+    }
+    push_pair(&mut output, "file", record.file);
+    push_pair(&mut output, "line", &record.line.to_string());
+    push_pair(&mut output, "pid", &process::id().to_string());
+    push_pair(&mut output, "thread", &record::thread_name());
+
+    for (key, value) in &record.context {
+        push_pair(&mut output, key, value);
+    }
+
+    #[cfg(feature = "structured")]
+    for (key, value) in record.fields.into_iter().flatten() {
+        match value {
+            serde_json::Value::String(value) => push_pair(&mut output, key, value),
+            value => push_pair(&mut output, key, &value.to_string()),
+        }
+    }
+
+    output
+}
+
+fn push_pair(output: &mut String, key: &str, value: &str) {
+    if !output.is_empty() {
+        output.push(' ');
+    }
+    push_key(output, key);
+    output.push('=');
+    push_value(output, value);
+}
+
+// Keys can't be quoted, anything that would end them early becomes an underscore
+fn push_key(output: &mut String, key: &str) {
+    if key.is_empty() {
+        output.push('_');
+        return;
+    }
+    output.extend(key.chars().map(|c| {
+        if c <= ' ' || c == '=' || c == '"' || c.is_control() {
+            '_'
+        } else {
+            c
+        }
+    }));
+}
+
+fn push_value(output: &mut String, value: &str) {
+    let needs_quotes = value.is_empty()
+        || value
+            .chars()
+            .any(|c| c <= ' ' || c == '=' || c == '"' || c == '\\' || c.is_control());
+    if !needs_quotes {
+        output.push_str(value);
+        return;
+    }
+
+    output.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if c.is_control() => {
+                write!(output, "\\u{:04x}", c as u32).ok();
+            }
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
@@ -1,13 +1,40 @@
 #[macro_export]
 macro_rules! log {
     ($level:expr, $($arg:tt)*) => {{
-        if $crate::logger().should_log($level) {
+        if $crate::logger().should_log_target($level, module_path!()) {
             let message = format!($($arg)*);
-            $crate::logger().write_log($level, &message, file!(), line!()).ok();
+            $crate::logger()
+                .write_log_with_target($level, &message, module_path!(), file!(), line!())
+                .ok();
         }
     }};
 }
 
+// Logs a byte buffer as a single multi-line record, takes a slice-like value or a
+// configured `Hexdump`, optionally followed by a title: `hexdump!(LogLevel::DEBUG, &buf, "leak")`
+#[macro_export]
+macro_rules! hexdump {
+    ($level:expr, $data:expr $(,)?) => {
+        $crate::hexdump!($level, $data, "Hexdump")
+    };
+    ($level:expr, $data:expr, $($arg:tt)+) => {{
+        if $crate::logger().should_log_target($level, module_path!()) {
+            let dump = $crate::Hexdump::from($data);
+            let message = format!("{} ({} bytes)\n{}", format!($($arg)+), dump.len(), dump);
+            $crate::logger()
+                .write_log_with_target($level, &message, module_path!(), file!(), line!())
+                .ok();
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        $crate::log!($crate::LogLevel::TRACE, $($arg)*);
+    };
+}
+
 #[macro_export]
 macro_rules! debug {
     ($($arg:tt)*) => {
@@ -65,27 +92,33 @@ macro_rules! time {
 macro_rules! event {
     // Base case with just message
     ($level:expr, $msg:expr) => {{
-        let event = $crate::LogEvent::new(
-            $level,
-            $msg.to_string(),
-            file!().to_string(),
-            line!(),
-        );
-        $crate::logger().write_structured_event(&event).ok();
+        if $crate::logger().should_log_target($level, module_path!()) {
+            let mut event = $crate::LogEvent::new(
+                $level,
+                $msg.to_string(),
+                file!().to_string(),
+                line!(),
+            );
+            event.set_target(module_path!());
+            $crate::logger().write_structured_event(&event).ok();
+        }
     }};
 
     // Message with fields
     ($level:expr, $msg:expr, {$($key:expr => $value:expr),* $(,)?}) => {{
-        let mut event = $crate::LogEvent::new(
-            $level,
-            $msg.to_string(),
-            file!().to_string(),
-            line!(),
-        );
-        $(
-            event.add_field($key, $value);
-        )*
-        $crate::logger().write_structured_event(&event).ok();
+        if $crate::logger().should_log_target($level, module_path!()) {
+            let mut event = $crate::LogEvent::new(
+                $level,
+                $msg.to_string(),
+                file!().to_string(),
+                line!(),
+            );
+            event.set_target(module_path!());
+            $(
+                event.add_field($key, $value);
+            )*
+            $crate::logger().write_structured_event(&event).ok();
+        }
     }};
 }
 
@@ -97,6 +130,14 @@ macro_rules! info_event {
     };
 }
 
+#[cfg(feature = "structured")]
+#[macro_export]
+macro_rules! trace_event {
+    ($($args:tt)*) => {
+        $crate::event!($crate::LogLevel::TRACE, $($args)*)
+    };
+}
+
 #[cfg(feature = "structured")]
 #[macro_export]
 macro_rules! debug_event {
@@ -0,0 +1,523 @@
+use crate::{levels::LogLevel, logger};
+use std::{
+    io::{self, Read, Write},
+    panic::{self, AssertUnwindSafe, Location},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+// Terminals get redrawn at most this often, tight loops calling `inc` stay cheap
+const REDRAW_INTERVAL: Duration = Duration::from_millis(50);
+// Files and pipes get a regular line this often instead
+const LINE_INTERVAL: Duration = Duration::from_secs(1);
+// Spinner frame rate
+const TICK_INTERVAL: Duration = Duration::from_millis(100);
+const BAR_WIDTH: usize = 30;
+// Byte throughput is an exponential moving average over samples this far apart,
+// each new sample weighing in with `RATE_SMOOTHING`
+const RATE_SAMPLE_INTERVAL: Duration = Duration::from_millis(100);
+const RATE_SMOOTHING: f64 = 0.3;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+// Progress indicator, redrawn in place on terminals as
+// `[>] Port scan [==========>         ]  52% 520/1000 173.2/s 00:03 ETA 00:02`,
+// or as an animated spinner `[◐] Waiting for connection 00:03` when given frames
+pub struct Progress {
+    id: u64,
+    state: Arc<Mutex<State>>,
+    // Where the progress was created, reported if it's dropped unfinished
+    location: &'static Location<'static>,
+    // Animates spinners while the caller is blocked
+    ticker: Option<JoinHandle<()>>,
+}
+
+struct State {
+    message: String,
+    total: Option<u64>,
+    current: u64,
+    started: Instant,
+    last_draw: Option<Instant>,
+    last_line: Option<Instant>,
+    // Spinner animation shown in place of the symbol, empty for plain bars
+    frames: Vec<String>,
+    frame: usize,
+    // Counts are bytes, shown as KiB/MiB/GiB with a smoothed throughput
+    bytes: bool,
+    rate: Option<f64>,
+    last_sample: (Instant, u64),
+    done: bool,
+}
+
+impl Progress {
+    // pwntools style spinner frames
+    pub const CIRCLE: [&'static str; 4] = ["◐", "◓", "◑", "◒"];
+    pub const ASCII: [&'static str; 4] = ["|", "/", "-", "\\"];
+
+    #[track_caller]
+    pub fn new<S: Into<String>>(message: S) -> Self {
+        Self::with_state(message.into(), None)
+    }
+
+    #[track_caller]
+    pub fn with_total<S: Into<String>>(message: S, total: u64) -> Self {
+        Self::with_state(message.into(), Some(total))
+    }
+
+    // Indeterminate progress with the `CIRCLE` animation
+    #[track_caller]
+    pub fn spinner<S: Into<String>>(message: S) -> Self {
+        Self::new(message).frames(Self::CIRCLE)
+    }
+
+    #[track_caller]
+    fn with_state(message: String, total: Option<u64>) -> Self {
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            location: Location::caller(),
+            state: Arc::new(Mutex::new(State {
+                message,
+                total,
+                current: 0,
+                started: Instant::now(),
+                last_draw: None,
+                last_line: None,
+                frames: Vec::new(),
+                frame: 0,
+                bytes: false,
+                rate: None,
+                last_sample: (Instant::now(), 0),
+                done: false,
+            })),
+            ticker: None,
+        }
+    }
+
+    // A panic while the state was locked must not take the final report down with it
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    // Turns the symbol into a spinner cycling through `frames`, e.g. `Progress::ASCII`
+    pub fn frames<I, S>(mut self, frames: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let frames: Vec<String> = frames.into_iter().map(Into::into).collect();
+        if frames.is_empty() {
+            return self;
+        }
+
+        {
+            let mut state = self.state();
+            state.frames = frames;
+            state.frame = 0;
+            state.draw(self.id, Instant::now());
+        }
+        if self.ticker.is_none() {
+            self.ticker = self.start_ticker();
+        }
+        self
+    }
+
+    // Counts bytes, e.g. `50.0 MiB/100.0 MiB 12.3 MiB/s`
+    pub fn bytes(self) -> Self {
+        self.state().bytes = true;
+        self
+    }
+
+    fn start_ticker(&self) -> Option<JoinHandle<()>> {
+        let id = self.id;
+        let state = Arc::clone(&self.state);
+        thread::Builder::new()
+            .name("hackerlog-spinner".into())
+            .spawn(move || loop {
+                thread::park_timeout(TICK_INTERVAL);
+                let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+                if state.done {
+                    break;
+                }
+                state.frame = (state.frame + 1) % state.frames.len();
+                state.draw(id, Instant::now());
+            })
+            .ok()
+    }
+
+    #[track_caller]
+    pub fn inc(&mut self, amount: u64) {
+        self.advance(amount, Location::caller());
+    }
+
+    fn advance(&mut self, amount: u64, location: &Location) {
+        let mut state = self.state();
+        state.current += amount;
+        state.sample(Instant::now());
+        state.tick(self.id, location);
+    }
+
+    #[track_caller]
+    pub fn update<S: Into<String>>(&mut self, message: S) {
+        let mut state = self.state();
+        state.message = message.into();
+        state.tick(self.id, Location::caller());
+    }
+
+    #[track_caller]
+    pub fn finish(self) {
+        let message = format!("{} [Complete]", self.state().message);
+        self.finish_with_message(message);
+    }
+
+    #[track_caller]
+    pub fn finish_with_message<S: Into<String>>(mut self, message: S) {
+        self.end(LogLevel::SUCCESS, &message.into(), Location::caller());
+    }
+
+    // Like `finish`, but leaves a FAILURE line
+    #[track_caller]
+    pub fn fail(self) {
+        let message = format!("{} [Failed]", self.state().message);
+        self.fail_with_message(message);
+    }
+
+    #[track_caller]
+    pub fn fail_with_message<S: Into<String>>(mut self, message: S) {
+        self.end(LogLevel::FAILURE, &message.into(), Location::caller());
+    }
+
+    // Runs `f` with the progress and finishes it afterwards. A panic inside `f` marks it
+    // failed and is handed back as `Err`, so the caller decides whether to resume it.
+    #[track_caller]
+    pub fn scope<F, R>(mut self, f: F) -> thread::Result<R>
+    where
+        F: FnOnce(&mut Progress) -> R,
+    {
+        let location = Location::caller();
+        match panic::catch_unwind(AssertUnwindSafe(|| f(&mut self))) {
+            Ok(value) => {
+                let message = format!("{} [Complete]", self.state().message);
+                self.end(LogLevel::SUCCESS, &message, location);
+                Ok(value)
+            }
+            Err(payload) => {
+                let reason = payload
+                    .downcast_ref::<&str>()
+                    .copied()
+                    .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                    .unwrap_or("panic");
+                let message = {
+                    let state = self.state();
+                    format!(
+                        "{} [Failed at {}: {}]",
+                        state.message,
+                        state.count(),
+                        reason
+                    )
+                };
+                self.end(LogLevel::FAILURE, &message, location);
+                Err(payload)
+            }
+        }
+    }
+
+    // Stops the spinner and replaces the status line with a final record
+    fn end(&mut self, level: LogLevel, message: &str, location: &Location) {
+        self.stop();
+        logger()
+            .write_log(level, message, location.file(), location.line())
+            .ok();
+    }
+
+    fn stop(&mut self) {
+        self.state().done = true;
+        // Joined before the line goes away, so the ticker can't draw it again
+        if let Some(ticker) = self.ticker.take() {
+            ticker.thread().unpark();
+            ticker.join().ok();
+        }
+        logger().set_live_line(self.id, None);
+    }
+}
+
+// Dropped without `finish` or `fail`, e.g. through `?`: reported as abandoned, or as failed
+// while the thread is panicking, along with the last count
+impl Drop for Progress {
+    fn drop(&mut self) {
+        let message = {
+            let state = self.state();
+            if state.done {
+                return;
+            }
+            let outcome = if thread::panicking() {
+                "Failed"
+            } else {
+                "Abandoned"
+            };
+            format!("{} [{} at {}]", state.message, outcome, state.count())
+        };
+
+        let level = if thread::panicking() {
+            LogLevel::FAILURE
+        } else {
+            LogLevel::WARN
+        };
+        self.end(level, &message, self.location);
+    }
+}
+
+impl State {
+    // `45/100`, or just `45` without a total
+    fn count(&self) -> String {
+        match self.total {
+            Some(total) => format!("{}/{}", self.amount(self.current), self.amount(total)),
+            None => self.amount(self.current),
+        }
+    }
+
+    fn tick(&mut self, id: u64, location: &Location) {
+        if !logger().should_log(LogLevel::INFO) {
+            return;
+        }
+
+        let now = Instant::now();
+        let due = |last: Option<Instant>, interval| {
+            last.is_none_or(|last| now.duration_since(last) >= interval)
+        };
+
+        // The completed bar is always drawn, the final line is left to `finish`
+        let done = self.total.is_some_and(|total| self.current >= total);
+        if done || due(self.last_draw, REDRAW_INTERVAL) {
+            self.draw(id, now);
+        }
+
+        if due(self.last_line, LINE_INTERVAL) {
+            self.last_line = Some(now);
+            logger()
+                .write_status(
+                    LogLevel::INFO,
+                    &self.status(false),
+                    location.file(),
+                    location.line(),
+                )
+                .ok();
+        }
+    }
+
+    fn draw(&mut self, id: u64, now: Instant) {
+        if !logger().should_log(LogLevel::INFO) {
+            return;
+        }
+
+        self.last_draw = Some(now);
+        let line = match self.frames.get(self.frame) {
+            Some(frame) => format!("[{}] {}", frame, self.status(true)),
+            None => format!("{} {}", LogLevel::INFO.symbol(), self.status(true)),
+        };
+        logger().set_live_line(id, Some(line));
+    }
+
+    fn sample(&mut self, now: Instant) {
+        let (last, counted) = self.last_sample;
+        let interval = now.duration_since(last);
+        if !self.bytes || interval < RATE_SAMPLE_INTERVAL {
+            return;
+        }
+
+        let rate = (self.current - counted) as f64 / interval.as_secs_f64();
+        self.rate = Some(match self.rate {
+            Some(smoothed) => smoothed + RATE_SMOOTHING * (rate - smoothed),
+            None => rate,
+        });
+        self.last_sample = (now, self.current);
+    }
+
+    fn amount(&self, amount: u64) -> String {
+        if self.bytes {
+            format_bytes(amount as f64)
+        } else {
+            amount.to_string()
+        }
+    }
+
+    fn status(&self, bar: bool) -> String {
+        let elapsed = self.started.elapsed();
+        let average = self.current as f64 / elapsed.as_secs_f64().max(0.001);
+        let (rate, throughput) = if self.bytes {
+            let rate = self.rate.unwrap_or(average);
+            (rate, format!("{}/s", format_bytes(rate)))
+        } else {
+            (average, format!("{:.1}/s", average))
+        };
+
+        let Some(total) = self.total else {
+            // Nothing counted yet, e.g. a spinner waiting on a socket
+            if self.current == 0 {
+                return format!("{} {}", self.message, format_time(elapsed));
+            }
+            return format!(
+                "{} [{}] {} {}",
+                self.message,
+                self.amount(self.current),
+                throughput,
+                format_time(elapsed)
+            );
+        };
+
+        let fraction = if total == 0 {
+            1.0
+        } else {
+            (self.current as f64 / total as f64).min(1.0)
+        };
+        // Way off estimates, e.g. right after starting on a huge total, don't fit a `Duration`
+        let remaining = total.saturating_sub(self.current) as f64 / rate;
+        let eta = match Duration::try_from_secs_f64(remaining) {
+            Ok(remaining) if rate > 0.0 => format_time(remaining),
+            _ => "--:--".to_string(),
+        };
+
+        let mut status = self.message.clone();
+        if bar {
+            let filled = (fraction * BAR_WIDTH as f64) as usize;
+            let head = if filled < BAR_WIDTH { ">" } else { "" };
+            status.push_str(&format!(
+                " [{}{}{}]",
+                "=".repeat(filled),
+                head,
+                " ".repeat(BAR_WIDTH.saturating_sub(filled + head.len()))
+            ));
+        }
+        status.push_str(&format!(
+            " {:>3}% {}/{} {} {} ETA {}",
+            (fraction * 100.0) as u64,
+            self.amount(self.current),
+            self.amount(total),
+            throughput,
+            format_time(elapsed),
+            eta
+        ));
+        status
+    }
+}
+
+// Pins several progress bars at the bottom of the terminal, in the order they were added.
+// Each bar can be moved to its own thread, log lines from anywhere print above the bars.
+#[derive(Default)]
+pub struct MultiProgress {
+    bars: Mutex<Vec<u64>>,
+}
+
+impl MultiProgress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Reserves the next line for `progress` and draws it right away
+    pub fn add(&self, progress: Progress) -> Progress {
+        self.bars.lock().unwrap().push(progress.id);
+        progress.state().draw(progress.id, Instant::now());
+        progress
+    }
+
+    // Removes the lines of bars that were never finished
+    pub fn clear(&self) {
+        for id in self.bars.lock().unwrap().drain(..) {
+            logger().set_live_line(id, None);
+        }
+    }
+}
+
+impl Drop for MultiProgress {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+// Advances a progress by the bytes read through it, e.g. `io::copy(&mut reader, &mut file)`
+pub struct ProgressReader<'a, R> {
+    inner: R,
+    progress: &'a mut Progress,
+}
+
+impl<'a, R: Read> ProgressReader<'a, R> {
+    pub fn new(inner: R, progress: &'a mut Progress) -> Self {
+        Self { inner, progress }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for ProgressReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        let location = self.progress.location;
+        self.progress.advance(read as u64, location);
+        Ok(read)
+    }
+}
+
+// Advances a progress by the bytes written through it
+pub struct ProgressWriter<'a, W> {
+    inner: W,
+    progress: &'a mut Progress,
+}
+
+impl<'a, W: Write> ProgressWriter<'a, W> {
+    pub fn new(inner: W, progress: &'a mut Progress) -> Self {
+        Self { inner, progress }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for ProgressWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        let location = self.progress.location;
+        self.progress.advance(written as u64, location);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+// Binary units, `512 B`, `12.3 MiB`
+fn format_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 5] = ["KiB", "MiB", "GiB", "TiB", "PiB"];
+    if bytes < 1024.0 {
+        return format!("{} B", bytes as u64);
+    }
+
+    let mut value = bytes / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+// `mm:ss`, or `h:mm:ss` past the hour
+fn format_time(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    if seconds >= 3600 {
+        format!(
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    } else {
+        format!("{:02}:{:02}", seconds / 60, seconds % 60)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{clock::Clock, levels::LogLevel};
+use chrono::{DateTime, FixedOffset};
+use std::{thread, time::Duration};
+
+#[cfg(feature = "structured")]
+use {serde_json::Value, std::collections::BTreeMap};
+
+// A single log call, captured once and rendered by every sink that accepts it
+pub struct Record<'a> {
+    pub(crate) level: LogLevel,
+    pub(crate) message: &'a str,
+    // Module path of the call site, or the `log` target for bridged records
+    pub(crate) target: &'a str,
+    pub(crate) file: &'a str,
+    pub(crate) line: u32,
+    // Sampled once per record, wall clock time is already in the logger timezone
+    pub(crate) clock: Clock,
+    pub(crate) context: Vec<(String, String)>,
+    #[cfg(feature = "structured")]
+    pub(crate) fields: Option<&'a BTreeMap<String, Value>>,
+}
+
+impl Record<'_> {
+    pub fn level(&self) -> LogLevel {
+        self.level
+    }
+
+    pub fn message(&self) -> &str {
+        self.message
+    }
+
+    pub fn target(&self) -> &str {
+        self.target
+    }
+
+    pub fn file(&self) -> &str {
+        self.file
+    }
+
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    pub fn time(&self) -> DateTime<FixedOffset> {
+        self.clock.time
+    }
+
+    // Since the logger was created or `reset_elapsed` was called
+    pub fn elapsed(&self) -> Duration {
+        self.clock.elapsed
+    }
+
+    // Since the previous record, see `DeltaScope`
+    pub fn delta(&self) -> Duration {
+        self.clock.delta
+    }
+
+    // Global context first, then the context of the logging thread
+    pub fn context(&self) -> &[(String, String)] {
+        &self.context
+    }
+
+    #[cfg(feature = "structured")]
+    pub fn field(&self, key: &str) -> Option<&Value> {
+        self.fields.and_then(|fields| fields.get(key))
+    }
+}
+
+pub(crate) fn thread_name() -> String {
+    thread::current().name().map_or_else(
+        || format!("Thread-{:?}", thread::current().id()),
+        ToString::to_string,
+    )
+}
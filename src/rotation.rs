@@ -0,0 +1,234 @@
+use chrono::{
+    format::{parse, Parsed, StrftimeItems},
+    DateTime, Local,
+};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+// When a log file gets rolled over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    // Roll over once the file would grow beyond this many bytes
+    Size(u64),
+    Hourly,
+    Daily,
+}
+
+// How rotated files are named
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RotationSuffix {
+    // scan.log.1 (newest), scan.log.2, ...
+    #[default]
+    Numbered,
+    // scan.log.2025-02-06 for daily, scan.log.2025-02-06-11 for hourly,
+    // scan.log.20250206T110115.123 for size based rotation
+    Timestamped,
+}
+
+pub struct RotatingFileWriter {
+    path: PathBuf,
+    rotation: Rotation,
+    suffix: RotationSuffix,
+    max_files: usize,
+    // Records arrive one per call, buffered so they don't cost a write each
+    file: BufWriter<File>,
+    size: u64,
+    period: Option<String>,
+}
+
+impl RotatingFileWriter {
+    pub fn new<P: AsRef<Path>>(path: P, rotation: Rotation) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let metadata = file.metadata()?;
+
+        // An existing file belongs to the period it was last written in, so a
+        // restart on the next day still rotates yesterday's log away
+        let opened_at = metadata
+            .modified()
+            .map(DateTime::<Local>::from)
+            .unwrap_or_else(|_| Local::now());
+
+        Ok(Self {
+            period: period_key(rotation, &opened_at),
+            path,
+            rotation,
+            suffix: RotationSuffix::default(),
+            max_files: 5,
+            file: BufWriter::new(file),
+            size: metadata.len(),
+        })
+    }
+
+    // Number of rotated files to keep around, older ones get deleted
+    pub fn max_files(mut self, max_files: usize) -> Self {
+        self.max_files = max_files;
+        self
+    }
+
+    pub fn suffix(mut self, suffix: RotationSuffix) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn should_rotate(&self, incoming: u64) -> bool {
+        match self.rotation {
+            // Never rotate an empty file, a single oversized record still has to go somewhere
+            Rotation::Size(limit) => self.size > 0 && self.size + incoming > limit,
+            Rotation::Hourly | Rotation::Daily => {
+                period_key(self.rotation, &Local::now()) != self.period
+            }
+        }
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        let file = if self.max_files == 0 {
+            File::create(&self.path)?
+        } else {
+            match self.suffix {
+                RotationSuffix::Numbered => self.rotate_numbered()?,
+                RotationSuffix::Timestamped => self.rotate_timestamped()?,
+            }
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?
+        };
+        self.file = BufWriter::new(file);
+
+        self.size = 0;
+        self.period = period_key(self.rotation, &Local::now());
+        Ok(())
+    }
+
+    fn rotate_numbered(&self) -> io::Result<()> {
+        let oldest = self.with_suffix(&self.max_files.to_string());
+        if oldest.exists() {
+            fs::remove_file(oldest)?;
+        }
+
+        for i in (1..self.max_files).rev() {
+            let from = self.with_suffix(&i.to_string());
+            if from.exists() {
+                fs::rename(from, self.with_suffix(&(i + 1).to_string()))?;
+            }
+        }
+
+        fs::rename(&self.path, self.with_suffix("1"))
+    }
+
+    fn rotate_timestamped(&self) -> io::Result<()> {
+        let stamp = match &self.period {
+            Some(period) => period.clone(),
+            None => Local::now().format("%Y%m%dT%H%M%S%.3f").to_string(),
+        };
+
+        let mut target = self.with_suffix(&stamp);
+        let mut n = 1;
+        while target.exists() {
+            target = self.with_suffix(&format!("{}.{}", stamp, n));
+            n += 1;
+        }
+        fs::rename(&self.path, target)?;
+
+        self.prune_timestamped()
+    }
+
+    fn prune_timestamped(&self) -> io::Result<()> {
+        let (dir, name) = self.split_path();
+        let prefix = format!("{}.", name);
+
+        // Only files named like the ones `rotate_timestamped` produces, `scan.conf` is left alone
+        let mut rotated: Vec<PathBuf> = fs::read_dir(&dir)?
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .strip_prefix(&prefix)
+                    .is_some_and(is_rotation_stamp)
+            })
+            .map(|entry| entry.path())
+            .collect();
+
+        // Timestamps sort lexicographically, oldest first
+        rotated.sort();
+        let excess = rotated.len().saturating_sub(self.max_files);
+        for path in rotated.into_iter().take(excess) {
+            fs::remove_file(path)?;
+        }
+
+        Ok(())
+    }
+
+    fn split_path(&self) -> (PathBuf, String) {
+        let dir = match self.path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        let name = self
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        (dir, name)
+    }
+
+    fn with_suffix(&self, suffix: &str) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(".");
+        path.push(suffix);
+        PathBuf::from(path)
+    }
+}
+
+fn period_key(rotation: Rotation, time: &DateTime<Local>) -> Option<String> {
+    match rotation {
+        Rotation::Size(_) => None,
+        Rotation::Hourly => Some(time.format("%Y-%m-%d-%H").to_string()),
+        Rotation::Daily => Some(time.format("%Y-%m-%d").to_string()),
+    }
+}
+
+const STAMP_FORMATS: [&str; 3] = ["%Y-%m-%d", "%Y-%m-%d-%H", "%Y%m%dT%H%M%S%.3f"];
+
+// A timestamp suffix, optionally followed by the `.N` added when the name was taken
+fn is_rotation_stamp(suffix: &str) -> bool {
+    let is_stamp = |stamp: &str| {
+        STAMP_FORMATS
+            .iter()
+            .any(|format| parse(&mut Parsed::new(), stamp, StrftimeItems::new(format)).is_ok())
+    };
+
+    is_stamp(suffix)
+        || suffix.rsplit_once('.').is_some_and(|(stamp, n)| {
+            !n.is_empty() && n.bytes().all(|byte| byte.is_ascii_digit()) && is_stamp(stamp)
+        })
+}
+
+impl Write for RotatingFileWriter {
+    // Every call is written in full to a single file. Sinks pass this writer each
+    // record in its own call, so a record is never split across two files.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.should_rotate(buf.len() as u64) {
+            self.rotate()?;
+        }
+
+        self.file.write_all(buf)?;
+        self.size += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
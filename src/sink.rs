@@ -0,0 +1,302 @@
+use crate::{
+    color::ColorMode, format::FormatTemplate, hexdump::strip_ansi, levels::LogLevel,
+    live::LiveRegion, record::Record,
+};
+use std::{
+    io::{self, IsTerminal, Write},
+    sync::{Arc, Mutex},
+};
+use termion::color;
+
+// Buffered, flushed according to the logger's `FlushPolicy`. Shared with the
+// background writer thread, which may still hold queued lines for it.
+pub(crate) type SharedWriter = Arc<Mutex<SinkWriter>>;
+
+pub(crate) fn shared_writer(writer: Box<dyn Write + Send>) -> SharedWriter {
+    Arc::new(Mutex::new(SinkWriter {
+        out: RecordBuffer::new(writer),
+        live: LiveRegion::default(),
+    }))
+}
+
+// Same capacity as a default `BufWriter`
+const BUFFER_CAPACITY: usize = 8 * 1024;
+
+// Buffers like a `BufWriter`, but hands the output on in the pieces it was written in.
+// Every record reaches the writer in a call of its own, which is what lets
+// `RotatingFileWriter` rotate by size without splitting records.
+struct RecordBuffer {
+    out: Box<dyn Write + Send>,
+    buffer: Vec<u8>,
+    // End offset of every piece in `buffer`
+    ends: Vec<usize>,
+}
+
+impl RecordBuffer {
+    fn new(out: Box<dyn Write + Send>) -> Self {
+        Self {
+            out,
+            buffer: Vec::with_capacity(BUFFER_CAPACITY),
+            ends: Vec::new(),
+        }
+    }
+
+    // Whatever failed to be written stays queued for the next attempt
+    fn write_queued(&mut self) -> io::Result<()> {
+        let mut result = Ok(());
+        let mut start = 0;
+        let mut written = 0;
+        for &end in &self.ends {
+            if let Err(err) = self.out.write_all(&self.buffer[start..end]) {
+                result = Err(err);
+                break;
+            }
+            start = end;
+            written += 1;
+        }
+
+        self.buffer.drain(..start);
+        self.ends.drain(..written);
+        for end in &mut self.ends {
+            *end -= start;
+        }
+        result
+    }
+}
+
+impl Write for RecordBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.buffer.len() + buf.len() > BUFFER_CAPACITY {
+            self.write_queued()?;
+        }
+        // Too big to be worth queueing
+        if buf.len() >= BUFFER_CAPACITY {
+            self.out.write_all(buf)?;
+            return Ok(buf.len());
+        }
+
+        self.buffer.extend_from_slice(buf);
+        self.ends.push(self.buffer.len());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_queued()?;
+        self.out.flush()
+    }
+}
+
+impl Drop for RecordBuffer {
+    fn drop(&mut self) {
+        self.write_queued().ok();
+    }
+}
+
+pub(crate) struct SinkWriter {
+    out: RecordBuffer,
+    // Only ever drawn on terminal sinks
+    live: LiveRegion,
+}
+
+impl SinkWriter {
+    // Writes a rendered line above the status lines, if there are any
+    pub(crate) fn write_line(&mut self, bytes: &[u8]) -> io::Result<()> {
+        if self.live.is_empty() {
+            return self.out.write_all(bytes);
+        }
+
+        self.live.clear(&mut self.out)?;
+        self.out.write_all(bytes)?;
+        self.live.draw(&mut self.out)
+    }
+
+    // Shows, replaces or (with `None`) removes the status line owned by `id`
+    pub(crate) fn set_live(&mut self, id: u64, line: Option<String>) -> io::Result<()> {
+        self.live.clear(&mut self.out)?;
+        self.live.set(id, line);
+        self.live.draw(&mut self.out)?;
+        // Status lines are never left sitting in the buffer
+        self.out.flush()
+    }
+
+    pub(crate) fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+pub(crate) const DEFAULT_SINK: &str = "default";
+pub(crate) const DEFAULT_FORMAT: &str = "{symbol} {context}{message}";
+pub(crate) const VERBOSE_FORMAT: &str =
+    "{symbol} ({time}) - [PID: {pid} | Thread: {thread}] - ({file}:{line}) : {context}{message}";
+
+// How a sink turns a record into a line
+pub(crate) enum OutputFormat {
+    Template(FormatTemplate),
+    #[cfg(feature = "structured")]
+    Json,
+    Logfmt,
+}
+
+impl OutputFormat {
+    pub(crate) fn render(&self, record: &Record) -> String {
+        match self {
+            Self::Template(template) => template.render(record),
+            #[cfg(feature = "structured")]
+            Self::Json => crate::structured::render_json(record),
+            Self::Logfmt => crate::logfmt::render_logfmt(record),
+        }
+    }
+}
+
+// A named output with its own layout, level threshold and color setting
+pub struct Sink {
+    pub(crate) name: String,
+    pub(crate) writer: SharedWriter,
+    pub(crate) format: OutputFormat,
+    // None lets every level through, leaving it to the logger wide filter
+    pub(crate) min_level: Option<LogLevel>,
+    // None follows the logger wide color mode
+    pub(crate) color: Option<ColorMode>,
+    pub(crate) is_terminal: bool,
+}
+
+impl Sink {
+    pub fn new<S, W>(name: S, writer: W) -> Self
+    where
+        S: Into<String>,
+        W: Write + Send + 'static,
+    {
+        Self::from_boxed(name, Box::new(writer))
+    }
+
+    pub fn stdout<S: Into<String>>(name: S) -> Self {
+        let is_terminal = io::stdout().is_terminal();
+        Self::new(name, io::stdout()).terminal(is_terminal)
+    }
+
+    pub fn stderr<S: Into<String>>(name: S) -> Self {
+        let is_terminal = io::stderr().is_terminal();
+        Self::new(name, io::stderr()).terminal(is_terminal)
+    }
+
+    pub(crate) fn from_boxed<S: Into<String>>(name: S, writer: Box<dyn Write + Send>) -> Self {
+        Self {
+            name: name.into(),
+            writer: shared_writer(writer),
+            format: OutputFormat::Template(FormatTemplate::parse(DEFAULT_FORMAT)),
+            min_level: None,
+            color: None,
+            // Arbitrary writers are treated as files, so `ColorMode::Auto` keeps them plain
+            is_terminal: false,
+        }
+    }
+
+    pub fn format(mut self, template: &str) -> Self {
+        self.format = OutputFormat::Template(FormatTemplate::parse(template));
+        self
+    }
+
+    // Same as `format`, for a template checked up front with `FormatTemplate::try_parse`
+    pub fn template(mut self, template: FormatTemplate) -> Self {
+        self.format = OutputFormat::Template(template);
+        self
+    }
+
+    // Writes JSON Lines, one object per record with event fields as typed values
+    #[cfg(feature = "structured")]
+    pub fn json(mut self) -> Self {
+        self.format = OutputFormat::Json;
+        self
+    }
+
+    // Writes logfmt, `ts=.. level=info msg="..."` followed by context and event fields
+    pub fn logfmt(mut self) -> Self {
+        self.format = OutputFormat::Logfmt;
+        self
+    }
+
+    pub fn min_level(mut self, level: LogLevel) -> Self {
+        self.min_level = Some(level);
+        self
+    }
+
+    pub fn color(mut self, mode: ColorMode) -> Self {
+        self.color = Some(mode);
+        self
+    }
+
+    // Marks the writer as an interactive terminal for `ColorMode::Auto`
+    pub fn terminal(mut self, is_terminal: bool) -> Self {
+        self.is_terminal = is_terminal;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    // Terminal sinks with a template can redraw status lines in place, the first of them does
+    pub(crate) fn shows_live(&self) -> bool {
+        self.is_terminal && matches!(self.format, OutputFormat::Template(_))
+    }
+
+    pub(crate) fn accepts(&self, level: LogLevel) -> bool {
+        self.min_level.is_none_or(|min_level| level >= min_level)
+    }
+
+    pub(crate) fn write_record(
+        &self,
+        record: &Record,
+        color_mode: ColorMode,
+        verbose: Option<&FormatTemplate>,
+    ) -> io::Result<()> {
+        let output = self.render_line(record, color_mode, verbose);
+        self.writer.lock().unwrap().write_line(output.as_bytes())
+    }
+
+    pub(crate) fn flush(&self) -> io::Result<()> {
+        self.writer.lock().unwrap().flush()
+    }
+
+    // The finished line including newline and color escapes
+    pub(crate) fn render_line(
+        &self,
+        record: &Record,
+        color_mode: ColorMode,
+        verbose: Option<&FormatTemplate>,
+    ) -> String {
+        // Escapes would corrupt JSON or logfmt, whatever the color mode says
+        let colored = matches!(self.format, OutputFormat::Template(_))
+            && self.color.unwrap_or(color_mode).enabled(self.is_terminal);
+
+        // Highlighting inside the message, e.g. from `hexdump!`, is only kept for colored output
+        let stripped;
+        let record = if !colored && record.message.contains('\x1b') {
+            stripped = strip_ansi(record.message);
+            &Record {
+                message: &stripped,
+                context: record.context.clone(),
+                ..*record
+            }
+        } else {
+            record
+        };
+
+        let mut output = match (&self.format, verbose) {
+            (OutputFormat::Template(_), Some(verbose)) => verbose.render(record),
+            (format, _) => format.render(record),
+        };
+        output.push('\n');
+
+        if colored {
+            output = format!(
+                "{}{}{}",
+                color::Fg(record.level.color()),
+                output,
+                color::Fg(color::Reset)
+            );
+        }
+
+        output
+    }
+}
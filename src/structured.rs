@@ -1,35 +1,78 @@
-#[cfg(feature = "structured")]
-pub mod structured {
-    use crate::levels::LogLevel;
-    use serde::Serialize;
-    use serde_json::Value;
-    use std::collections::BTreeMap;
-
-    #[derive(Debug, Clone)]
-    pub struct LogEvent {
-        pub(crate) level: LogLevel,
-        pub(crate) message: String,
-        pub(crate) file: String,
-        pub(crate) line: u32,
-        pub(crate) fields: BTreeMap<String, Value>,
-    }
+use crate::{levels::LogLevel, record::Record};
+use chrono::SecondsFormat;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::{collections::BTreeMap, process};
+
+#[derive(Debug, Clone)]
+pub struct LogEvent {
+    pub(crate) level: LogLevel,
+    pub(crate) message: String,
+    pub(crate) target: String,
+    pub(crate) file: String,
+    pub(crate) line: u32,
+    pub(crate) fields: BTreeMap<String, Value>,
+}
 
-    impl LogEvent {
-        pub fn new(level: LogLevel, message: String, file: String, line: u32) -> Self {
-            Self {
-                level,
-                message,
-                file,
-                line,
-                fields: BTreeMap::new(),
-            }
+impl LogEvent {
+    pub fn new(level: LogLevel, message: String, file: String, line: u32) -> Self {
+        Self {
+            level,
+            message,
+            target: String::new(),
+            file,
+            line,
+            fields: BTreeMap::new(),
         }
+    }
+
+    // Module path used for filtering and the `{target}` placeholder
+    pub fn set_target(&mut self, target: &str) -> &mut Self {
+        self.target = target.to_string();
+        self
+    }
 
-        pub fn add_field<T: Serialize>(&mut self, key: &str, value: T) -> &mut Self {
-            if let Ok(value) = serde_json::to_value(value) {
-                self.fields.insert(key.to_string(), value);
-            }
-            self
+    pub fn add_field<T: Serialize>(&mut self, key: &str, value: T) -> &mut Self {
+        if let Ok(value) = serde_json::to_value(value) {
+            self.fields.insert(key.to_string(), value);
         }
+        self
     }
 }
+
+// One JSON object per record, see https://jsonlines.org
+pub(crate) fn render_json(record: &Record) -> String {
+    let mut object = Map::new();
+    object.insert(
+        "timestamp".into(),
+        record
+            .clock
+            .time
+            .to_rfc3339_opts(SecondsFormat::Millis, false)
+            .into(),
+    );
+    object.insert("level".into(), record.level.name().into());
+    object.insert("message".into(), record.message.into());
+    object.insert("target".into(), record.target.into());
+    object.insert("file".into(), record.file.into());
+    object.insert("line".into(), record.line.into());
+    object.insert("pid".into(), process::id().into());
+    object.insert("thread".into(), crate::record::thread_name().into());
+
+    let context: Map<String, Value> = record
+        .context
+        .iter()
+        .map(|(key, value)| (key.clone(), Value::from(value.as_str())))
+        .collect();
+    object.insert("context".into(), Value::Object(context));
+
+    let fields: Map<String, Value> = record
+        .fields
+        .into_iter()
+        .flatten()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    object.insert("fields".into(), Value::Object(fields));
+
+    Value::Object(object).to_string()
+}
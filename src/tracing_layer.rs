@@ -0,0 +1,200 @@
+use crate::{context, levels::LogLevel, logger, record::Record};
+use std::fmt;
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id},
+    Event, Level, Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+#[cfg(feature = "structured")]
+use {serde_json::Value, std::collections::BTreeMap};
+
+// Renders `tracing` events through the global hackerlog logger. Span fields
+// show up in `{context}`, event fields become `LogEvent` style fields.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HackerlogLayer;
+
+impl HackerlogLayer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+// Stored in the span extensions, rendered root first for every event inside the span
+struct SpanFields(Vec<(String, String)>);
+
+#[derive(Default)]
+struct ContextVisitor(Vec<(String, String)>);
+
+impl ContextVisitor {
+    fn push(&mut self, field: &Field, value: String) {
+        match self.0.iter_mut().find(|(key, _)| key == field.name()) {
+            Some(entry) => entry.1 = value,
+            None => self.0.push((field.name().to_string(), value)),
+        }
+    }
+}
+
+impl Visit for ContextVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.push(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.push(field, format!("{:?}", value));
+    }
+}
+
+#[derive(Default)]
+struct EventVisitor {
+    message: String,
+    #[cfg(feature = "structured")]
+    fields: BTreeMap<String, Value>,
+    #[cfg(not(feature = "structured"))]
+    fields: Vec<(String, String)>,
+}
+
+impl EventVisitor {
+    #[cfg(feature = "structured")]
+    fn push<T: Into<Value>>(&mut self, field: &Field, value: T) {
+        self.fields.insert(field.name().to_string(), value.into());
+    }
+
+    #[cfg(not(feature = "structured"))]
+    fn push<T: fmt::Debug>(&mut self, field: &Field, value: T) {
+        self.push_debug(field, &value);
+    }
+
+    // `?value` fields, kept as their Debug output rather than a quoted string
+    #[cfg(feature = "structured")]
+    fn push_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.push(field, format!("{:?}", value));
+    }
+
+    #[cfg(not(feature = "structured"))]
+    fn push_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.fields
+            .push((field.name().to_string(), format!("{:?}", value)));
+    }
+}
+
+impl Visit for EventVisitor {
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.push(field, value);
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.push(field, value);
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.push(field, value);
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.push(field, value);
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.push(field, value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            self.push_debug(field, value);
+        }
+    }
+}
+
+const fn to_level(level: Level) -> LogLevel {
+    match level {
+        Level::ERROR => LogLevel::ERROR,
+        Level::WARN => LogLevel::WARN,
+        Level::INFO => LogLevel::INFO,
+        Level::DEBUG => LogLevel::DEBUG,
+        Level::TRACE => LogLevel::TRACE,
+    }
+}
+
+impl<S> Layer<S> for HackerlogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = ContextVisitor::default();
+        attrs.record(&mut visitor);
+
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanFields(visitor.0));
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &tracing::span::Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let mut extensions = span.extensions_mut();
+        let mut visitor = ContextVisitor(
+            extensions
+                .remove::<SpanFields>()
+                .map(|fields| fields.0)
+                .unwrap_or_default(),
+        );
+        values.record(&mut visitor);
+        extensions.insert(SpanFields(visitor.0));
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let level = to_level(*metadata.level());
+        let logger = logger();
+        if !logger.should_log_target(level, metadata.target()) {
+            return;
+        }
+
+        let mut visitor = EventVisitor::default();
+        event.record(&mut visitor);
+
+        let mut context = context::capture();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                if let Some(fields) = span.extensions().get::<SpanFields>() {
+                    context.extend(fields.0.iter().cloned());
+                }
+            }
+        }
+
+        // Without typed fields the event fields get folded into the message
+        #[cfg(not(feature = "structured"))]
+        if !visitor.fields.is_empty() {
+            let fields: Vec<String> = visitor
+                .fields
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect();
+            visitor.message = format!("{} [{}]", visitor.message, fields.join(", "));
+        }
+
+        logger
+            .dispatch(Record {
+                level,
+                message: &visitor.message,
+                target: metadata.target(),
+                file: metadata.file().unwrap_or("<unknown>"),
+                line: metadata.line().unwrap_or(0),
+                clock: logger.clock.sample(),
+                context,
+                #[cfg(feature = "structured")]
+                fields: Some(&visitor.fields),
+            })
+            .ok();
+    }
+}